@@ -0,0 +1,365 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde_json::{json, Value};
+use wordfreq::{Float, Standardizer, Tokenizer, WordFreq};
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "wordfreq",
+    version,
+    about = "Looks up the frequencies of words in many languages"
+)]
+struct Args {
+    /// Language tag for standardization and tokenization.
+    #[arg(short, long, global = true, default_value = "en")]
+    lang: String,
+
+    /// Path to a word list.
+    #[arg(short, long, global = true)]
+    wordlist: Option<PathBuf>,
+
+    /// Format of the word list, detected from the file extension if not specified.
+    #[arg(short, long, global = true)]
+    format: Option<Format>,
+
+    /// Output format.
+    #[arg(short, long, global = true, default_value = "tsv")]
+    output: Output,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Prints the frequencies of words.
+    Lookup {
+        /// Words, read from stdin if not given.
+        words: Vec<String>,
+    },
+    /// Prints the Zipf frequencies of words.
+    Zipf {
+        /// Words, read from stdin if not given.
+        words: Vec<String>,
+    },
+    /// Prints the most frequent words in the word list.
+    Top {
+        /// Number of words.
+        #[arg(short, default_value_t = 10)]
+        n: usize,
+    },
+    /// Splits text into tokens, using the word list as the dictionary if given.
+    Tokenize {
+        /// Texts, read from stdin if not given.
+        texts: Vec<String>,
+    },
+    /// Standardizes words as done before looking them up.
+    Standardize {
+        /// Words, read from stdin if not given.
+        words: Vec<String>,
+    },
+    /// Converts the word list into another format.
+    Convert {
+        /// Path to the output word list.
+        path: PathBuf,
+
+        /// Format of the output, detected from the file extension if not specified.
+        #[arg(long)]
+        to: Option<Format>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Lines of `<word> <weight>`, compressed with Zstandard if the extension is `.zst`.
+    Text,
+    /// The format of `WordFreq::serialize()`.
+    Bincode,
+    /// The cB format of Python's wordfreq.
+    Msgpack,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Output {
+    Tsv,
+    Json,
+}
+
+impl Format {
+    fn detect(path: &Path) -> Result<Self> {
+        let name = path.to_string_lossy();
+        if name.ends_with(".txt") || name.ends_with(".txt.zst") {
+            Ok(Self::Text)
+        } else if name.ends_with(".bin") || name.ends_with(".bincode") {
+            Ok(Self::Bincode)
+        } else if name.ends_with(".msgpack") || name.ends_with(".msgpack.gz") {
+            Ok(Self::Msgpack)
+        } else {
+            Err(anyhow!(
+                "The format of {name} cannot be detected. Specify it explicitly."
+            ))
+        }
+    }
+}
+
+fn is_zst(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "zst")
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    run(&args, &mut out)?;
+    out.flush()?;
+    Ok(())
+}
+
+fn run<W: Write>(args: &Args, out: &mut W) -> Result<()> {
+    match &args.command {
+        Command::Lookup { words } => {
+            let wf = load_wordfreq(args)?;
+            let rows = read_inputs(words)?
+                .into_iter()
+                .map(|word| {
+                    let freq = wf.word_frequency(&word);
+                    (word, freq.to_string())
+                })
+                .collect();
+            write_pairs(out, args.output, ("frequency", true), rows)
+        }
+        Command::Zipf { words } => {
+            let wf = load_wordfreq(args)?;
+            let rows = read_inputs(words)?
+                .into_iter()
+                .map(|word| {
+                    let zipf = wf.zipf_frequency(&word);
+                    (word, zipf.to_string())
+                })
+                .collect();
+            write_pairs(out, args.output, ("zipf", true), rows)
+        }
+        Command::Top { n } => {
+            let wf = load_wordfreq(args)?;
+            let mut word_freqs: Vec<_> = wf.word_frequency_map().iter().collect();
+            word_freqs.sort_unstable_by(|(w1, f1), (w2, f2)| f2.total_cmp(f1).then(w1.cmp(w2)));
+            let rows = word_freqs
+                .into_iter()
+                .take(*n)
+                .map(|(word, &freq)| (word.clone(), freq.to_string()))
+                .collect();
+            write_pairs(out, args.output, ("frequency", true), rows)
+        }
+        Command::Tokenize { texts } => {
+            let mut tokenizer = Tokenizer::new(&args.lang)?;
+            if args.wordlist.is_some() {
+                tokenizer = tokenizer.dictionary(&load_wordfreq(args)?);
+            }
+            let tokens: Vec<_> = read_inputs(texts)?
+                .iter()
+                .map(|text| tokenizer.tokenize(text))
+                .collect();
+            match args.output {
+                Output::Tsv => {
+                    for tokens in tokens {
+                        writeln!(out, "{}", tokens.join("\t"))?;
+                    }
+                }
+                Output::Json => writeln!(out, "{}", json!(tokens))?,
+            }
+            Ok(())
+        }
+        Command::Standardize { words } => {
+            let standardizer = Standardizer::new(&args.lang)?;
+            let rows = read_inputs(words)?
+                .into_iter()
+                .map(|word| {
+                    let standardized = standardizer.apply(&word);
+                    (word, standardized)
+                })
+                .collect();
+            write_pairs(out, args.output, ("standardized", false), rows)
+        }
+        Command::Convert { path, to } => {
+            let wf = load_wordfreq(args)?;
+            let format = to.map_or_else(|| Format::detect(path), Ok)?;
+            save_wordfreq(&wf, path, format)
+        }
+    }
+}
+
+/// Returns the inputs, or the lines from stdin if empty.
+fn read_inputs(inputs: &[String]) -> Result<Vec<String>> {
+    if !inputs.is_empty() {
+        return Ok(inputs.to_vec());
+    }
+    let mut lines = vec![];
+    for line in io::stdin().lock().lines() {
+        let line = line?;
+        let line = line.trim();
+        if !line.is_empty() {
+            lines.push(line.to_string());
+        }
+    }
+    Ok(lines)
+}
+
+/// Writes pairs of words and their values, where `column` is the name of the values
+/// and whether they are numbers.
+fn write_pairs<W: Write>(
+    out: &mut W,
+    output: Output,
+    column: (&str, bool),
+    rows: Vec<(String, String)>,
+) -> Result<()> {
+    let (name, numeric) = column;
+    match output {
+        Output::Tsv => {
+            for (word, value) in rows {
+                writeln!(out, "{word}\t{value}")?;
+            }
+        }
+        Output::Json => {
+            let mut objects = Vec::with_capacity(rows.len());
+            for (word, value) in rows {
+                // Parses the shortest representation of Float, not to print noisy digits of f64.
+                let value = if numeric {
+                    serde_json::from_str(&value)?
+                } else {
+                    Value::String(value)
+                };
+                objects.push(json!({ "word": word, name: value }));
+            }
+            writeln!(out, "{}", Value::Array(objects))?;
+        }
+    }
+    Ok(())
+}
+
+fn load_wordfreq(args: &Args) -> Result<WordFreq> {
+    let path = args
+        .wordlist
+        .as_ref()
+        .ok_or_else(|| anyhow!("--wordlist is required for this subcommand."))?;
+    let format = args.format.map_or_else(|| Format::detect(path), Ok)?;
+    let file = File::open(path)?;
+    let wf = match format {
+        Format::Text => {
+            let rdr: Box<dyn Read> = if is_zst(path) {
+                Box::new(zstd::Decoder::new(file)?)
+            } else {
+                Box::new(file)
+            };
+            WordFreq::new(wordfreq::word_weights_from_text(BufReader::new(rdr))?)
+        }
+        Format::Bincode => {
+            let mut bytes = vec![];
+            BufReader::new(file).read_to_end(&mut bytes)?;
+            WordFreq::deserialize(&bytes)?
+        }
+        Format::Msgpack => WordFreq::from_cb_msgpack(file)?,
+    };
+    Ok(wf.standardizer(Standardizer::new(&args.lang)?))
+}
+
+fn save_wordfreq(wf: &WordFreq, path: &Path, format: Format) -> Result<()> {
+    let wtr = BufWriter::new(File::create(path)?);
+    match format {
+        Format::Text => {
+            if is_zst(path) {
+                let mut encoder = zstd::Encoder::new(wtr, 0)?;
+                write_text(wf, &mut encoder)?;
+                encoder.finish()?.flush()?;
+            } else {
+                write_text(wf, wtr)?;
+            }
+        }
+        Format::Bincode => {
+            let mut wtr = wtr;
+            wtr.write_all(&wf.serialize()?)?;
+            wtr.flush()?;
+        }
+        Format::Msgpack => wf.to_cb_msgpack(wtr)?,
+    }
+    Ok(())
+}
+
+fn write_text<W: Write>(wf: &WordFreq, mut wtr: W) -> Result<()> {
+    let mut word_freqs: Vec<(&String, &Float)> = wf.word_frequency_map().iter().collect();
+    word_freqs.sort_unstable_by(|(w1, f1), (w2, f2)| f2.total_cmp(f1).then(w1.cmp(w2)));
+    for (word, freq) in word_freqs {
+        writeln!(wtr, "{word} {freq}")?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_args(args: &[&str]) -> String {
+        let args = Args::try_parse_from(["wordfreq"].iter().chain(args)).unwrap();
+        let mut out = vec![];
+        run(&args, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(Format::detect(Path::new("a.txt")).unwrap(), Format::Text);
+        assert_eq!(
+            Format::detect(Path::new("a.txt.zst")).unwrap(),
+            Format::Text
+        );
+        assert_eq!(Format::detect(Path::new("a.bin")).unwrap(), Format::Bincode);
+        assert_eq!(
+            Format::detect(Path::new("large_en.msgpack.gz")).unwrap(),
+            Format::Msgpack
+        );
+        assert!(Format::detect(Path::new("a.csv")).is_err());
+    }
+
+    #[test]
+    fn test_commands() {
+        let dir = std::env::temp_dir().join(format!("wordfreq-cli-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let text = dir.join("example.txt");
+        std::fs::write(&text, "las 10\nvegas 30\n").unwrap();
+        let text = text.to_str().unwrap();
+
+        assert_eq!(
+            run_args(&["--wordlist", text, "lookup", "Las", "nevada"]),
+            "Las\t0.25\nnevada\t0\n"
+        );
+        assert_eq!(
+            run_args(&["--wordlist", text, "-o", "json", "zipf", "vegas"]),
+            "[{\"word\":\"vegas\",\"zipf\":8.88}]\n"
+        );
+        assert_eq!(
+            run_args(&["--wordlist", text, "top", "-n", "1"]),
+            "vegas\t0.75\n"
+        );
+        assert_eq!(
+            run_args(&["--lang", "tr", "standardize", "İstanbul"]),
+            "İstanbul\tistanbul\n"
+        );
+        assert_eq!(
+            run_args(&["tokenize", "I don't have 2,000 dollars."]),
+            "I\tdon't\thave\t2,000\tdollars\n"
+        );
+
+        for ext in ["txt.zst", "bin", "msgpack.gz"] {
+            let converted = dir.join(format!("converted.{ext}"));
+            let converted = converted.to_str().unwrap();
+            run_args(&["--wordlist", text, "convert", converted]);
+            let top = run_args(&["--wordlist", converted, "top", "-n", "1"]);
+            assert!(top.starts_with("vegas\t0.7"), "{ext}: {top}");
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,112 @@
+#ifndef WORDFREQ_H
+#define WORDFREQ_H
+
+/* This file is generated by cbindgen. Do not edit it manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Opaque handle of a standardizer.
+typedef struct Standardizer Standardizer;
+
+// Opaque handle of a word frequency model.
+typedef struct WordFreq WordFreq;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Returns the message of the last error in the calling thread, or `NULL` if no error has occurred.
+//
+// The returned string is owned by the library and valid until the next failing call in the thread.
+const char *wordfreq_last_error(void);
+
+// Creates an instance from a buffer serialized by `WordFreq::serialize()`,
+// returning `NULL` on failure.
+//
+// # Safety
+//
+// `data` must point to `len` readable bytes.
+struct WordFreq *wordfreq_from_bytes(const uint8_t *data, size_t len);
+
+// Creates an instance from a buffer of lines `<word> <weight>`, returning `NULL` on failure.
+//
+// # Safety
+//
+// `data` must point to `len` readable bytes.
+struct WordFreq *wordfreq_from_text(const uint8_t *data, size_t len);
+
+// Loads a pre-compiled model from the name of its feature, such as `large-en`,
+// returning `NULL` on failure.
+//
+// The standardizer of the language is set up as in `wordfreq_model::load_wordfreq()`.
+//
+// # Safety
+//
+// `name` must be a NUL-terminated string.
+struct WordFreq *wordfreq_load_model(const char *name);
+
+// Releases the instance. Nothing is done if `wf` is `NULL`.
+//
+// # Safety
+//
+// `wf` must be `NULL` or an instance created by this library that has not been released.
+void wordfreq_free(struct WordFreq *wf);
+
+// Sets the standardizer of the language to the instance, returning 0 on success or -1 on failure.
+//
+// # Safety
+//
+// `wf` must be a valid instance, and `lang` must be a NUL-terminated string.
+int wordfreq_set_standardizer(struct WordFreq *wf, const char *lang);
+
+// Returns the frequency of the word, or a negative value on failure.
+//
+// # Safety
+//
+// `wf` must be a valid instance, and `word` must be a NUL-terminated string.
+float wordfreq_word_frequency(const struct WordFreq *wf, const char *word);
+
+// Returns the Zipf frequency of the word, or a negative value on failure.
+//
+// # Safety
+//
+// `wf` must be a valid instance, and `word` must be a NUL-terminated string.
+float wordfreq_zipf_frequency(const struct WordFreq *wf, const char *word);
+
+// Creates a standardizer of the language, returning `NULL` on failure.
+//
+// # Safety
+//
+// `lang` must be a NUL-terminated string.
+struct Standardizer *wordfreq_standardizer_new(const char *lang);
+
+// Standardizes the word, returning a new string or `NULL` on failure.
+//
+// # Safety
+//
+// `standardizer` must be a valid standardizer, and `word` must be a NUL-terminated string.
+char *wordfreq_standardizer_apply(const struct Standardizer *standardizer, const char *word);
+
+// Releases the standardizer. Nothing is done if `standardizer` is `NULL`.
+//
+// # Safety
+//
+// `standardizer` must be `NULL` or a standardizer created by this library that has not been released.
+void wordfreq_standardizer_free(struct Standardizer *standardizer);
+
+// Releases a string returned by this library. Nothing is done if `s` is `NULL`.
+//
+// # Safety
+//
+// `s` must be `NULL` or a string returned by this library that has not been released.
+void wordfreq_string_free(char *s);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* WORDFREQ_H */
@@ -0,0 +1,349 @@
+//! # wordfreq-ffi
+//!
+//! C bindings of [wordfreq], so that the models can be used from C, C++, Go, and so on.
+//!
+//! The header `include/wordfreq.h` is generated by [cbindgen](https://github.com/mozilla/cbindgen).
+//! Build this crate as a static or dynamic library and link it with your program.
+//!
+//! ## Models
+//!
+//! An instance of [`WordFreq`] is created with one of the following functions:
+//!
+//! - [`wordfreq_from_bytes()`] from a buffer serialized by [`wordfreq::WordFreq::serialize()`],
+//! - [`wordfreq_from_text()`] from a buffer in the format of [`wordfreq::word_weights_from_text()`], or
+//! - [`wordfreq_load_model()`] from the name of a [`wordfreq_model::ModelKind`], such as `large-en`.
+//!
+//! The pre-compiled models have to be enabled with the features of wordfreq-model,
+//! e.g., `cargo build --release -p wordfreq-ffi --features wordfreq-model/large-en`.
+//! Instances must be released with [`wordfreq_free()`].
+//!
+//! ## Errors
+//!
+//! Functions return `NULL`, `-1`, or a negative frequency on failure.
+//! The message of the last error in the calling thread can be obtained with [`wordfreq_last_error()`].
+//! Panics in the library are caught and reported as errors, since unwinding across
+//! the C boundary is undefined behavior.
+//!
+//! ## Strings
+//!
+//! All the strings passed to the functions must be NUL-terminated UTF-8.
+//! Strings returned by the functions must be released with [`wordfreq_string_free()`],
+//! except for [`wordfreq_last_error()`].
+use std::cell::RefCell;
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+use anyhow::{anyhow, Result};
+
+/// Opaque handle of a word frequency model.
+pub struct WordFreq(wordfreq::WordFreq);
+
+/// Opaque handle of a standardizer.
+pub struct Standardizer(wordfreq::Standardizer);
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(e: &anyhow::Error) {
+    // Interior NUL bytes cannot be represented in C strings.
+    let msg = CString::new(e.to_string().replace('\0', "")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(msg));
+}
+
+/// Runs the function, converting a panic into an error.
+fn catch_panic<T, F>(f: F) -> Result<T>
+where
+    F: FnOnce() -> Result<T>,
+{
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let msg = payload
+            .downcast_ref::<&str>()
+            .map(|msg| msg.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown error".to_string());
+        Err(anyhow!("A panic occurred: {msg}"))
+    })
+}
+
+/// Boxes the value, or records the error and returns `NULL`.
+fn into_raw_or_null<T>(result: Result<T>) -> *mut T {
+    match result {
+        Ok(value) => Box::into_raw(Box::new(value)),
+        Err(e) => {
+            set_last_error(&e);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Borrows a NUL-terminated UTF-8 string.
+unsafe fn borrow_str<'a>(s: *const c_char) -> Result<&'a str> {
+    if s.is_null() {
+        return Err(anyhow!("The string must not be NULL."));
+    }
+    Ok(CStr::from_ptr(s).to_str()?)
+}
+
+/// Borrows a buffer of bytes.
+unsafe fn borrow_bytes<'a>(data: *const u8, len: usize) -> Result<&'a [u8]> {
+    if data.is_null() {
+        return if len == 0 {
+            Ok(&[])
+        } else {
+            Err(anyhow!("The buffer must not be NULL."))
+        };
+    }
+    Ok(std::slice::from_raw_parts(data, len))
+}
+
+unsafe fn borrow_wf<'a>(wf: *const WordFreq) -> Result<&'a wordfreq::WordFreq> {
+    wf.as_ref()
+        .map(|wf| &wf.0)
+        .ok_or_else(|| anyhow!("The instance must not be NULL."))
+}
+
+/// Returns the message of the last error in the calling thread, or `NULL` if no error has occurred.
+///
+/// The returned string is owned by the library and valid until the next failing call in the thread.
+#[no_mangle]
+pub extern "C" fn wordfreq_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(ptr::null(), |msg| msg.as_ptr())
+    })
+}
+
+/// Creates an instance from a buffer serialized by `WordFreq::serialize()`,
+/// returning `NULL` on failure.
+///
+/// # Safety
+///
+/// `data` must point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn wordfreq_from_bytes(data: *const u8, len: usize) -> *mut WordFreq {
+    into_raw_or_null(catch_panic(|| {
+        let bytes = borrow_bytes(data, len)?;
+        Ok(WordFreq(wordfreq::WordFreq::deserialize(bytes)?))
+    }))
+}
+
+/// Creates an instance from a buffer of lines `<word> <weight>`, returning `NULL` on failure.
+///
+/// # Safety
+///
+/// `data` must point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn wordfreq_from_text(data: *const u8, len: usize) -> *mut WordFreq {
+    into_raw_or_null(catch_panic(|| {
+        let bytes = borrow_bytes(data, len)?;
+        let word_weights = wordfreq::word_weights_from_text(bytes)?;
+        Ok(WordFreq(wordfreq::WordFreq::new(word_weights)))
+    }))
+}
+
+/// Loads a pre-compiled model from the name of its feature, such as `large-en`,
+/// returning `NULL` on failure.
+///
+/// The standardizer of the language is set up as in `wordfreq_model::load_wordfreq()`.
+///
+/// # Safety
+///
+/// `name` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wordfreq_load_model(name: *const c_char) -> *mut WordFreq {
+    into_raw_or_null(catch_panic(|| {
+        let kind = borrow_str(name)?.parse()?;
+        Ok(WordFreq(wordfreq_model::load_wordfreq(kind)?))
+    }))
+}
+
+/// Releases the instance. Nothing is done if `wf` is `NULL`.
+///
+/// # Safety
+///
+/// `wf` must be `NULL` or an instance created by this library that has not been released.
+#[no_mangle]
+pub unsafe extern "C" fn wordfreq_free(wf: *mut WordFreq) {
+    if !wf.is_null() {
+        free_or_set_error(|| drop(Box::from_raw(wf)));
+    }
+}
+
+/// Releases an object, recording the error if a panic occurs.
+fn free_or_set_error<F>(f: F)
+where
+    F: FnOnce(),
+{
+    if let Err(e) = catch_panic(|| {
+        f();
+        Ok(())
+    }) {
+        set_last_error(&e);
+    }
+}
+
+/// Sets the standardizer of the language to the instance, returning 0 on success or -1 on failure.
+///
+/// # Safety
+///
+/// `wf` must be a valid instance, and `lang` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wordfreq_set_standardizer(
+    wf: *mut WordFreq,
+    lang: *const c_char,
+) -> c_int {
+    let result = catch_panic(|| {
+        let lang = borrow_str(lang)?;
+        let wf = wf
+            .as_mut()
+            .ok_or_else(|| anyhow!("The instance must not be NULL."))?;
+        let standardizer = wordfreq::Standardizer::new(lang)?;
+        // The builder method takes the instance by value.
+        let model = std::mem::replace(&mut wf.0, wordfreq::WordFreq::new::<_, &str>([]));
+        wf.0 = model.standardizer(standardizer);
+        Ok(())
+    });
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            set_last_error(&e);
+            -1
+        }
+    }
+}
+
+/// Returns the frequency of the word, or a negative value on failure.
+///
+/// # Safety
+///
+/// `wf` must be a valid instance, and `word` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wordfreq_word_frequency(wf: *const WordFreq, word: *const c_char) -> f32 {
+    frequency_or_negative(wf, word, |wf, word| wf.word_frequency(word))
+}
+
+/// Returns the Zipf frequency of the word, or a negative value on failure.
+///
+/// # Safety
+///
+/// `wf` must be a valid instance, and `word` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wordfreq_zipf_frequency(wf: *const WordFreq, word: *const c_char) -> f32 {
+    frequency_or_negative(wf, word, |wf, word| wf.zipf_frequency(word))
+}
+
+unsafe fn frequency_or_negative<F>(wf: *const WordFreq, word: *const c_char, f: F) -> f32
+where
+    F: FnOnce(&wordfreq::WordFreq, &str) -> wordfreq::Float,
+{
+    match catch_panic(|| Ok(f(borrow_wf(wf)?, borrow_str(word)?))) {
+        Ok(freq) => freq,
+        Err(e) => {
+            set_last_error(&e);
+            -1.
+        }
+    }
+}
+
+/// Creates a standardizer of the language, returning `NULL` on failure.
+///
+/// # Safety
+///
+/// `lang` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wordfreq_standardizer_new(lang: *const c_char) -> *mut Standardizer {
+    into_raw_or_null(catch_panic(|| {
+        let lang = borrow_str(lang)?;
+        Ok(Standardizer(wordfreq::Standardizer::new(lang)?))
+    }))
+}
+
+/// Standardizes the word, returning a new string or `NULL` on failure.
+///
+/// # Safety
+///
+/// `standardizer` must be a valid standardizer, and `word` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wordfreq_standardizer_apply(
+    standardizer: *const Standardizer,
+    word: *const c_char,
+) -> *mut c_char {
+    let result = catch_panic(|| {
+        let standardizer = standardizer
+            .as_ref()
+            .ok_or_else(|| anyhow!("The standardizer must not be NULL."))?;
+        let standardized = standardizer.0.apply(borrow_str(word)?);
+        Ok(CString::new(standardized)?)
+    });
+    match result {
+        Ok(s) => s.into_raw(),
+        Err(e) => {
+            set_last_error(&e);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Releases the standardizer. Nothing is done if `standardizer` is `NULL`.
+///
+/// # Safety
+///
+/// `standardizer` must be `NULL` or a standardizer created by this library that has not been released.
+#[no_mangle]
+pub unsafe extern "C" fn wordfreq_standardizer_free(standardizer: *mut Standardizer) {
+    if !standardizer.is_null() {
+        free_or_set_error(|| drop(Box::from_raw(standardizer)));
+    }
+}
+
+/// Releases a string returned by this library. Nothing is done if `s` is `NULL`.
+///
+/// # Safety
+///
+/// `s` must be `NULL` or a string returned by this library that has not been released.
+#[no_mangle]
+pub unsafe extern "C" fn wordfreq_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        free_or_set_error(|| drop(CString::from_raw(s)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_from_bytes() {
+        let bytes = wordfreq::WordFreq::new([("las", 10.), ("vegas", 30.)])
+            .serialize()
+            .unwrap();
+        unsafe {
+            let wf = wordfreq_from_bytes(bytes.as_ptr(), bytes.len());
+            assert!(!wf.is_null());
+            assert_relative_eq!(wordfreq_word_frequency(wf, c"vegas".as_ptr()), 0.75);
+            assert_relative_eq!(wordfreq_zipf_frequency(wf, c"vegas".as_ptr()), 8.88);
+            wordfreq_free(wf);
+
+            assert!(wordfreq_from_bytes(ptr::null(), 1).is_null());
+            let msg = CStr::from_ptr(wordfreq_last_error()).to_str().unwrap();
+            assert_eq!(msg, "The buffer must not be NULL.");
+        }
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        let result = catch_panic::<(), _>(|| panic!("boom"));
+        assert_eq!(result.unwrap_err().to_string(), "A panic occurred: boom");
+        let result = catch_panic::<(), _>(|| panic!("{}", "formatted"));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "A panic occurred: formatted"
+        );
+        assert_eq!(catch_panic(|| Ok(1)).unwrap(), 1);
+    }
+}
@@ -0,0 +1,85 @@
+// Test harness of the C bindings, run by tests/c_harness.rs or the Makefile.
+#include <math.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "wordfreq.h"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                    \
+    do {                                                               \
+        if (!(cond)) {                                                 \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,     \
+                    __LINE__, #cond);                                  \
+            failures++;                                                \
+        }                                                              \
+    } while (0)
+
+#define CHECK_NEAR(a, b) CHECK(fabsf((a) - (b)) < 1e-5f)
+
+static void test_from_text(void) {
+    const char *text = "las 10\nvegas 30\n";
+    WordFreq *wf = wordfreq_from_text((const uint8_t *)text, strlen(text));
+    CHECK(wf != NULL);
+    CHECK_NEAR(wordfreq_word_frequency(wf, "las"), 0.25f);
+    CHECK_NEAR(wordfreq_word_frequency(wf, "vegas"), 0.75f);
+    CHECK_NEAR(wordfreq_word_frequency(wf, "Las"), 0.0f);
+    CHECK_NEAR(wordfreq_zipf_frequency(wf, "vegas"), 8.88f);
+
+    CHECK(wordfreq_set_standardizer(wf, "en") == 0);
+    CHECK_NEAR(wordfreq_word_frequency(wf, "Las"), 0.25f);
+    CHECK(wordfreq_set_standardizer(wf, "xx") == -1);
+    CHECK(wordfreq_last_error() != NULL);
+    wordfreq_free(wf);
+}
+
+static void test_load_model(void) {
+    WordFreq *wf = wordfreq_load_model("example-en");
+    CHECK(wf != NULL);
+    CHECK_NEAR(wordfreq_word_frequency(wf, "Las"), 0.25f);
+    wordfreq_free(wf);
+
+    CHECK(wordfreq_load_model("large-xx") == NULL);
+    CHECK(strstr(wordfreq_last_error(), "large-xx") != NULL);
+}
+
+static void test_errors(void) {
+    const uint8_t bytes[] = {0xff, 0xff};
+    CHECK(wordfreq_from_bytes(bytes, sizeof(bytes)) == NULL);
+    CHECK(wordfreq_last_error() != NULL);
+
+    const char *text = "las\n";
+    CHECK(wordfreq_from_text((const uint8_t *)text, strlen(text)) == NULL);
+
+    WordFreq *wf = wordfreq_load_model("example-en");
+    CHECK(wordfreq_word_frequency(wf, "\xff") < 0.0f);
+    CHECK(wordfreq_word_frequency(wf, NULL) < 0.0f);
+    CHECK(wordfreq_zipf_frequency(NULL, "las") < 0.0f);
+    wordfreq_free(wf);
+    wordfreq_free(NULL);
+}
+
+static void test_standardizer(void) {
+    Standardizer *standardizer = wordfreq_standardizer_new("tr");
+    CHECK(standardizer != NULL);
+    char *standardized = wordfreq_standardizer_apply(standardizer, "İstanbul");
+    CHECK(standardized != NULL && strcmp(standardized, "istanbul") == 0);
+    wordfreq_string_free(standardized);
+    wordfreq_standardizer_free(standardizer);
+
+    CHECK(wordfreq_standardizer_new("xx") == NULL);
+}
+
+int main(void) {
+    test_from_text();
+    test_load_model();
+    test_errors();
+    test_standardizer();
+    if (failures != 0) {
+        fprintf(stderr, "%d check(s) failed\n", failures);
+        return 1;
+    }
+    printf("ok\n");
+    return 0;
+}
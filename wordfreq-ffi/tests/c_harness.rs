@@ -0,0 +1,35 @@
+// Compiles tests/c/test_wordfreq.c against the static library and runs it.
+#![cfg(target_os = "linux")]
+
+use std::path::PathBuf;
+use std::process::Command;
+
+#[test]
+fn test_c_harness() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // The static library is placed next to the deps directory of this test binary.
+    let exe = std::env::current_exe().unwrap();
+    let target_dir = exe.parent().unwrap().parent().unwrap();
+    let lib = target_dir.join("libwordfreq_ffi.a");
+    assert!(lib.exists(), "{} is not found", lib.display());
+
+    let bin = target_dir.join("test_wordfreq_c");
+    let status = Command::new(std::env::var("CC").unwrap_or_else(|_| "cc".to_string()))
+        .args(["-Wall", "-Wextra", "-Werror", "-I"])
+        .arg(manifest_dir.join("include"))
+        .arg("-o")
+        .arg(&bin)
+        .arg(manifest_dir.join("tests/c/test_wordfreq.c"))
+        .arg(&lib)
+        .args(["-lpthread", "-ldl", "-lm"])
+        .status()
+        .expect("a C compiler is required");
+    assert!(status.success());
+
+    let output = Command::new(&bin).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,17 @@
+// Checks that include/wordfreq.h is up to date.
+use std::path::PathBuf;
+
+#[test]
+fn test_header() {
+    let crate_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml")).unwrap();
+    let mut generated = vec![];
+    cbindgen::generate_with_config(&crate_dir, config)
+        .unwrap()
+        .write(&mut generated);
+    let committed = std::fs::read(crate_dir.join("include/wordfreq.h")).unwrap();
+    assert!(
+        generated == committed,
+        "include/wordfreq.h is outdated. Run `cbindgen --config cbindgen.toml --output include/wordfreq.h`."
+    );
+}
@@ -118,6 +118,12 @@ with open('src/lib.rs', 'wt') as f:
     for wordlist, lang in targets:
         model_kind_block.append(f'{TAB}#[cfg(feature = "{wordlist}-{lang}")]')
         model_kind_block.append(f'{TAB}{wordlist.capitalize()}{lang.capitalize()},')
+    from_str_block = []
+    for wordlist, lang in targets:
+        from_str_block.append(f'{TAB}{TAB}{TAB}#[cfg(feature = "{wordlist}-{lang}")]')
+        from_str_block.append(
+            f'{TAB}{TAB}{TAB}"{wordlist}-{lang}" => Ok(Self::{wordlist.capitalize()}{lang.capitalize()}),'
+        )
     match_block = []
     for wordlist, lang in targets:
         match_block.append(f'{TAB}{TAB}#[cfg(feature = "{wordlist}-{lang}")]')
@@ -132,6 +138,7 @@ with open('src/lib.rs', 'wt') as f:
         lib_rs.format(
             model_kind_block='\n'.join(model_kind_block),
             const_block='\n'.join(const_block),
+            from_str_block='\n'.join(from_str_block),
             match_block='\n'.join(match_block),
         )
     )
@@ -43,6 +43,16 @@
 //! assert_relative_eq!(wf.word_frequency("Las"), 0.25); // Standardized
 //! ```
 //!
+//! A [`ModelKind`] value can also be parsed from its feature name, such as `"example-en"`.
+//!
+//! ```
+//! use wordfreq_model::ModelKind;
+//!
+//! let kind: ModelKind = "example-en".parse().unwrap();
+//! assert!(matches!(kind, ModelKind::ExampleEn));
+//! assert!("large-xx".parse::<ModelKind>().is_err());
+//! ```
+//!
 //! ## Standardization
 //!
 //! As the above example shows, the model automatically standardizes words before looking them up (i.e., `Las` is handled as `las`).
@@ -58,8 +68,9 @@
 //! If you do not desire automatic model downloads and binary embedding, you can create instances from these files directly.
 //! See the instructions in [wordfreq].
 use std::env;
+use std::str::FromStr;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use wordfreq::Standardizer;
 use wordfreq::WordFreq;
 
@@ -330,6 +341,144 @@ const DATA_SMALL_VI: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/small_vi.
 #[cfg(feature = "small-zh")]
 const DATA_SMALL_ZH: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/small_zh.bin"));
 
+impl FromStr for ModelKind {
+    type Err = anyhow::Error;
+
+    /// Parses a feature name such as `large-en`, which must be enabled.
+    fn from_str(name: &str) -> Result<Self> {
+        match name {
+            "example-en" => Ok(Self::ExampleEn),
+            #[cfg(feature = "large-ar")]
+            "large-ar" => Ok(Self::LargeAr),
+            #[cfg(feature = "large-bn")]
+            "large-bn" => Ok(Self::LargeBn),
+            #[cfg(feature = "large-ca")]
+            "large-ca" => Ok(Self::LargeCa),
+            #[cfg(feature = "large-cs")]
+            "large-cs" => Ok(Self::LargeCs),
+            #[cfg(feature = "large-de")]
+            "large-de" => Ok(Self::LargeDe),
+            #[cfg(feature = "large-en")]
+            "large-en" => Ok(Self::LargeEn),
+            #[cfg(feature = "large-es")]
+            "large-es" => Ok(Self::LargeEs),
+            #[cfg(feature = "large-fi")]
+            "large-fi" => Ok(Self::LargeFi),
+            #[cfg(feature = "large-fr")]
+            "large-fr" => Ok(Self::LargeFr),
+            #[cfg(feature = "large-he")]
+            "large-he" => Ok(Self::LargeHe),
+            #[cfg(feature = "large-it")]
+            "large-it" => Ok(Self::LargeIt),
+            #[cfg(feature = "large-ja")]
+            "large-ja" => Ok(Self::LargeJa),
+            #[cfg(feature = "large-mk")]
+            "large-mk" => Ok(Self::LargeMk),
+            #[cfg(feature = "large-nb")]
+            "large-nb" => Ok(Self::LargeNb),
+            #[cfg(feature = "large-nl")]
+            "large-nl" => Ok(Self::LargeNl),
+            #[cfg(feature = "large-pl")]
+            "large-pl" => Ok(Self::LargePl),
+            #[cfg(feature = "large-pt")]
+            "large-pt" => Ok(Self::LargePt),
+            #[cfg(feature = "large-ru")]
+            "large-ru" => Ok(Self::LargeRu),
+            #[cfg(feature = "large-sv")]
+            "large-sv" => Ok(Self::LargeSv),
+            #[cfg(feature = "large-uk")]
+            "large-uk" => Ok(Self::LargeUk),
+            #[cfg(feature = "large-zh")]
+            "large-zh" => Ok(Self::LargeZh),
+            #[cfg(feature = "small-ar")]
+            "small-ar" => Ok(Self::SmallAr),
+            #[cfg(feature = "small-bg")]
+            "small-bg" => Ok(Self::SmallBg),
+            #[cfg(feature = "small-bn")]
+            "small-bn" => Ok(Self::SmallBn),
+            #[cfg(feature = "small-ca")]
+            "small-ca" => Ok(Self::SmallCa),
+            #[cfg(feature = "small-cs")]
+            "small-cs" => Ok(Self::SmallCs),
+            #[cfg(feature = "small-da")]
+            "small-da" => Ok(Self::SmallDa),
+            #[cfg(feature = "small-de")]
+            "small-de" => Ok(Self::SmallDe),
+            #[cfg(feature = "small-el")]
+            "small-el" => Ok(Self::SmallEl),
+            #[cfg(feature = "small-en")]
+            "small-en" => Ok(Self::SmallEn),
+            #[cfg(feature = "small-es")]
+            "small-es" => Ok(Self::SmallEs),
+            #[cfg(feature = "small-fa")]
+            "small-fa" => Ok(Self::SmallFa),
+            #[cfg(feature = "small-fi")]
+            "small-fi" => Ok(Self::SmallFi),
+            #[cfg(feature = "small-fil")]
+            "small-fil" => Ok(Self::SmallFil),
+            #[cfg(feature = "small-fr")]
+            "small-fr" => Ok(Self::SmallFr),
+            #[cfg(feature = "small-he")]
+            "small-he" => Ok(Self::SmallHe),
+            #[cfg(feature = "small-hi")]
+            "small-hi" => Ok(Self::SmallHi),
+            #[cfg(feature = "small-hu")]
+            "small-hu" => Ok(Self::SmallHu),
+            #[cfg(feature = "small-id")]
+            "small-id" => Ok(Self::SmallId),
+            #[cfg(feature = "small-is")]
+            "small-is" => Ok(Self::SmallIs),
+            #[cfg(feature = "small-it")]
+            "small-it" => Ok(Self::SmallIt),
+            #[cfg(feature = "small-ja")]
+            "small-ja" => Ok(Self::SmallJa),
+            #[cfg(feature = "small-ko")]
+            "small-ko" => Ok(Self::SmallKo),
+            #[cfg(feature = "small-lt")]
+            "small-lt" => Ok(Self::SmallLt),
+            #[cfg(feature = "small-lv")]
+            "small-lv" => Ok(Self::SmallLv),
+            #[cfg(feature = "small-mk")]
+            "small-mk" => Ok(Self::SmallMk),
+            #[cfg(feature = "small-ms")]
+            "small-ms" => Ok(Self::SmallMs),
+            #[cfg(feature = "small-nb")]
+            "small-nb" => Ok(Self::SmallNb),
+            #[cfg(feature = "small-nl")]
+            "small-nl" => Ok(Self::SmallNl),
+            #[cfg(feature = "small-pl")]
+            "small-pl" => Ok(Self::SmallPl),
+            #[cfg(feature = "small-pt")]
+            "small-pt" => Ok(Self::SmallPt),
+            #[cfg(feature = "small-ro")]
+            "small-ro" => Ok(Self::SmallRo),
+            #[cfg(feature = "small-ru")]
+            "small-ru" => Ok(Self::SmallRu),
+            #[cfg(feature = "small-sh")]
+            "small-sh" => Ok(Self::SmallSh),
+            #[cfg(feature = "small-sk")]
+            "small-sk" => Ok(Self::SmallSk),
+            #[cfg(feature = "small-sl")]
+            "small-sl" => Ok(Self::SmallSl),
+            #[cfg(feature = "small-sv")]
+            "small-sv" => Ok(Self::SmallSv),
+            #[cfg(feature = "small-ta")]
+            "small-ta" => Ok(Self::SmallTa),
+            #[cfg(feature = "small-tr")]
+            "small-tr" => Ok(Self::SmallTr),
+            #[cfg(feature = "small-uk")]
+            "small-uk" => Ok(Self::SmallUk),
+            #[cfg(feature = "small-ur")]
+            "small-ur" => Ok(Self::SmallUr),
+            #[cfg(feature = "small-vi")]
+            "small-vi" => Ok(Self::SmallVi),
+            #[cfg(feature = "small-zh")]
+            "small-zh" => Ok(Self::SmallZh),
+            _ => Err(anyhow!("{name} is an unknown or disabled model kind.")),
+        }
+    }
+}
+
 /// Loads a pre-compiled [`WordFreq`] model, setting up an appropriate [`Standardizer`] instance.
 pub fn load_wordfreq(kind: ModelKind) -> Result<WordFreq> {
     match kind {
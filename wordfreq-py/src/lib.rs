@@ -0,0 +1,303 @@
+//! # wordfreq-py
+//!
+//! Python bindings of [wordfreq], whose module-level functions have the same signatures
+//! as those of [Python's wordfreq](https://github.com/rspeer/wordfreq), so that pipelines
+//! can switch to this implementation and cross-check the results.
+//!
+//! ```python
+//! import wordfreq_rs
+//!
+//! wordfreq_rs.word_frequency("cafe", "en")
+//! wordfreq_rs.zipf_frequency("café", "fr", wordlist="large")
+//! wordfreq_rs.top_n_list("en", 10)
+//! ```
+//!
+//! The models are loaded with [`wordfreq_model::load_wordfreq()`], so they have to be enabled
+//! with the features of wordfreq-model, e.g.,
+//! `maturin develop --release --features wordfreq-model/large-en`.
+//!
+//! The classes `WordFreq` and `Standardizer` wrap those in [wordfreq] for custom models.
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use anyhow::{anyhow, Result};
+use pyo3::exceptions::{PyLookupError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use wordfreq::{Float, Tokenizer};
+use wordfreq_model::ModelKind;
+
+// Chinese phrases are segmented by a dictionary, so their frequencies are penalized
+// by this factor for each inferred space, as in Python's wordfreq.
+const INFERRED_SPACE_FACTOR: f64 = 10.;
+
+/// Model of a word list, with the tokenizer for phrases.
+struct Model {
+    wf: wordfreq::WordFreq,
+    tokenizer: Tokenizer,
+    chinese: bool,
+    sorted_words: OnceLock<Vec<String>>,
+}
+
+impl Model {
+    fn new(wf: wordfreq::WordFreq, lang: &str) -> Result<Self> {
+        let chinese = lang == "zh";
+        let mut tokenizer = Tokenizer::new(lang)?;
+        if chinese {
+            tokenizer = tokenizer.dictionary(&wf);
+        }
+        Ok(Self {
+            wf,
+            tokenizer,
+            chinese,
+            sorted_words: OnceLock::new(),
+        })
+    }
+
+    /// Returns the frequency of a word or phrase in the same manner as `word_frequency`
+    /// of Python's wordfreq.
+    ///
+    /// The frequency of a phrase is the half-harmonic-mean of the frequencies of its tokens,
+    /// rounded to three significant digits.
+    fn word_frequency(&self, word: &str, minimum: f64) -> f64 {
+        let tokens = self.tokenizer.tokenize(word);
+        if tokens.is_empty() {
+            return minimum;
+        }
+        let mut one_over_result = 0.;
+        for token in &tokens {
+            let freq = f64::from(self.wf.word_frequency(token));
+            if freq == 0. {
+                return minimum;
+            }
+            one_over_result += 1. / freq;
+        }
+        let mut freq = 1. / one_over_result;
+        if self.chinese {
+            freq /= INFERRED_SPACE_FACTOR.powi(tokens.len() as i32 - 1);
+        }
+        let unrounded = freq.max(minimum);
+        if unrounded == 0. {
+            return 0.;
+        }
+        let leading_zeroes = (-unrounded.log10()).floor() as i32;
+        round(unrounded, leading_zeroes + 3)
+    }
+
+    /// Returns the Zipf frequency in the same manner as `zipf_frequency` of Python's wordfreq.
+    fn zipf_frequency(&self, word: &str, minimum: f64) -> f64 {
+        let freq_min = 10f64.powf(minimum - 9.);
+        let freq = self.word_frequency(word, freq_min);
+        round(freq.log10() + 9., 2)
+    }
+
+    /// Returns the words in descending order of frequency, breaking ties alphabetically.
+    fn sorted_words(&self) -> &[String] {
+        self.sorted_words.get_or_init(|| {
+            let mut word_freqs: Vec<_> = self.wf.word_frequency_map().iter().collect();
+            word_freqs.sort_unstable_by(|(w1, f1), (w2, f2)| f2.total_cmp(f1).then(w1.cmp(w2)));
+            word_freqs.into_iter().map(|(w, _)| w.clone()).collect()
+        })
+    }
+
+    fn top_n_list(&self, n: usize, ascii_only: bool) -> Vec<String> {
+        self.sorted_words()
+            .iter()
+            .filter(|w| !ascii_only || w.chars().all(|c| c <= '~'))
+            .take(n)
+            .cloned()
+            .collect()
+    }
+}
+
+fn round(x: f64, places: i32) -> f64 {
+    let multiplier = 10f64.powi(places);
+    (x * multiplier).round() / multiplier
+}
+
+/// Returns the primary language subtag, such as `en` for `en-US`.
+fn primary_language(lang: &str) -> String {
+    lang.split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+/// Returns the model kind of the word list, where `best` means `large` if enabled or `small`.
+fn model_kind(lang: &str, wordlist: &str) -> Result<ModelKind> {
+    let candidates: &[&str] = match wordlist {
+        "best" => &["large", "small"],
+        "large" => &["large"],
+        "small" => &["small"],
+        _ => return Err(anyhow!("{wordlist} is an unknown word list.")),
+    };
+    candidates
+        .iter()
+        .find_map(|size| format!("{size}-{lang}").parse().ok())
+        .ok_or_else(|| {
+            anyhow!("There is no {wordlist} word list for {lang}. Enable it with the features of wordfreq-model.")
+        })
+}
+
+/// Loaded models keyed by pairs of a language and a word list.
+type Models = Mutex<HashMap<(String, String), Arc<Model>>>;
+
+fn get_model(lang: &str, wordlist: &str) -> PyResult<Arc<Model>> {
+    static MODELS: OnceLock<Models> = OnceLock::new();
+
+    let lang = primary_language(lang);
+    let key = (lang.clone(), wordlist.to_string());
+    let models = MODELS.get_or_init(Default::default);
+    if let Some(model) = models.lock().unwrap().get(&key) {
+        return Ok(Arc::clone(model));
+    }
+    // Loads the model without the lock, because it takes a while.
+    let kind = model_kind(&lang, wordlist).map_err(|e| PyLookupError::new_err(e.to_string()))?;
+    let model = wordfreq_model::load_wordfreq(kind)
+        .and_then(|wf| Model::new(wf, &lang))
+        .map_err(to_py_err)?;
+    let model = Arc::new(model);
+    models.lock().unwrap().insert(key, Arc::clone(&model));
+    Ok(model)
+}
+
+fn to_py_err(e: anyhow::Error) -> PyErr {
+    PyValueError::new_err(e.to_string())
+}
+
+/// Returns the frequency of a word or phrase in the language, between 0 and 1.
+#[pyfunction]
+#[pyo3(signature = (word, lang, wordlist = "best", minimum = 0.0))]
+fn word_frequency(word: &str, lang: &str, wordlist: &str, minimum: f64) -> PyResult<f64> {
+    Ok(get_model(lang, wordlist)?.word_frequency(word, minimum))
+}
+
+/// Returns the Zipf frequency of a word or phrase in the language.
+#[pyfunction]
+#[pyo3(signature = (word, lang, wordlist = "best", minimum = 0.0))]
+fn zipf_frequency(word: &str, lang: &str, wordlist: &str, minimum: f64) -> PyResult<f64> {
+    Ok(get_model(lang, wordlist)?.zipf_frequency(word, minimum))
+}
+
+/// Returns the `n` most frequent words in the language.
+#[pyfunction]
+#[pyo3(signature = (lang, n, wordlist = "best", ascii_only = false))]
+fn top_n_list(lang: &str, n: usize, wordlist: &str, ascii_only: bool) -> PyResult<Vec<String>> {
+    Ok(get_model(lang, wordlist)?.top_n_list(n, ascii_only))
+}
+
+/// Loads a pre-compiled model from the name of its feature, such as `large-en`.
+#[pyfunction]
+fn load_wordfreq(name: &str) -> PyResult<WordFreq> {
+    let kind: ModelKind = name
+        .parse()
+        .map_err(|e: anyhow::Error| PyLookupError::new_err(e.to_string()))?;
+    let wf = wordfreq_model::load_wordfreq(kind).map_err(to_py_err)?;
+    Ok(WordFreq(wf))
+}
+
+/// Wrapper of [`wordfreq::WordFreq`].
+#[pyclass(module = "wordfreq_rs", frozen)]
+struct WordFreq(wordfreq::WordFreq);
+
+#[pymethods]
+impl WordFreq {
+    #[new]
+    #[pyo3(signature = (word_weights, standardizer = None))]
+    fn new(word_weights: Vec<(String, Float)>, standardizer: Option<&Standardizer>) -> Self {
+        let mut wf = wordfreq::WordFreq::new(word_weights);
+        if let Some(standardizer) = standardizer {
+            wf = wf.standardizer(standardizer.0.clone());
+        }
+        Self(wf)
+    }
+
+    /// Creates an instance from bytes serialized by `WordFreq::serialize()` in Rust.
+    #[staticmethod]
+    fn from_bytes(bytes: &[u8]) -> PyResult<Self> {
+        Ok(Self(
+            wordfreq::WordFreq::deserialize(bytes).map_err(to_py_err)?,
+        ))
+    }
+
+    /// Returns the serialized bytes.
+    fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let bytes = self.0.serialize().map_err(to_py_err)?;
+        Ok(PyBytes::new(py, &bytes))
+    }
+
+    fn word_frequency(&self, word: &str) -> Float {
+        self.0.word_frequency(word)
+    }
+
+    fn zipf_frequency(&self, word: &str) -> Float {
+        self.0.zipf_frequency(word)
+    }
+}
+
+/// Wrapper of [`wordfreq::Standardizer`].
+#[pyclass(module = "wordfreq_rs", frozen)]
+struct Standardizer(wordfreq::Standardizer);
+
+#[pymethods]
+impl Standardizer {
+    #[new]
+    fn new(lang: &str) -> PyResult<Self> {
+        Ok(Self(wordfreq::Standardizer::new(lang).map_err(to_py_err)?))
+    }
+
+    fn apply(&self, word: &str) -> String {
+        self.0.apply(word)
+    }
+}
+
+#[pymodule]
+fn wordfreq_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(word_frequency, m)?)?;
+    m.add_function(wrap_pyfunction!(zipf_frequency, m)?)?;
+    m.add_function(wrap_pyfunction!(top_n_list, m)?)?;
+    m.add_function(wrap_pyfunction!(load_wordfreq, m)?)?;
+    m.add_class::<WordFreq>()?;
+    m.add_class::<Standardizer>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    fn example() -> Model {
+        let wf = wordfreq::WordFreq::new([("las", 10.), ("vegas", 30.), ("café", 10.)])
+            .standardizer(wordfreq::Standardizer::new("en").unwrap());
+        Model::new(wf, "en").unwrap()
+    }
+
+    #[test]
+    fn test_word_frequency() {
+        let model = example();
+        assert_relative_eq!(model.word_frequency("Las", 0.), 0.2);
+        // 1 / (1 / 0.2 + 1 / 0.6) = 0.15
+        assert_relative_eq!(model.word_frequency("Las Vegas!", 0.), 0.15);
+        assert_relative_eq!(model.word_frequency("las reno", 0.1), 0.1);
+        assert_relative_eq!(model.word_frequency("...", 0.), 0.);
+        assert_relative_eq!(model.zipf_frequency("vegas", 0.), 8.78);
+        assert_relative_eq!(model.zipf_frequency("reno", 3.), 3.);
+    }
+
+    #[test]
+    fn test_top_n_list() {
+        let model = example();
+        assert_eq!(model.top_n_list(2, false), vec!["vegas", "café"]);
+        assert_eq!(model.top_n_list(5, true), vec!["vegas", "las"]);
+    }
+
+    #[test]
+    fn test_model_kind() {
+        assert!(model_kind("xx", "best").is_err());
+        assert!(model_kind("en", "medium").is_err());
+        assert_eq!(primary_language("en-US"), "en");
+        assert_eq!(primary_language("pt_BR"), "pt");
+    }
+}
@@ -0,0 +1,69 @@
+"""Tests of the Python bindings, run with `pytest` after `maturin develop`.
+
+The parity tests compare the results with Python's wordfreq, and they are skipped
+unless wordfreq is installed and the `large-en` or `small-en` model is enabled.
+"""
+import pytest
+
+import wordfreq_rs
+
+
+def test_wordfreq_class():
+    wf = wordfreq_rs.WordFreq([("las", 10.0), ("vegas", 30.0)])
+    assert wf.word_frequency("las") == pytest.approx(0.25)
+    assert wf.word_frequency("Las") == 0.0
+    assert wf.zipf_frequency("vegas") == pytest.approx(8.88)
+
+    wf = wordfreq_rs.WordFreq.from_bytes(wf.to_bytes())
+    assert wf.word_frequency("vegas") == pytest.approx(0.75)
+
+    wf = wordfreq_rs.WordFreq([("las", 10.0)], standardizer=wordfreq_rs.Standardizer("en"))
+    assert wf.word_frequency("Las") == pytest.approx(1.0)
+
+
+def test_standardizer():
+    assert wordfreq_rs.Standardizer("tr").apply("İstanbul") == "istanbul"
+    with pytest.raises(ValueError):
+        wordfreq_rs.Standardizer("xx")
+
+
+def test_load_wordfreq():
+    wf = wordfreq_rs.load_wordfreq("example-en")
+    assert wf.word_frequency("Las") == pytest.approx(0.25)
+    with pytest.raises(LookupError):
+        wordfreq_rs.load_wordfreq("large-xx")
+
+
+def test_unknown_wordlist():
+    with pytest.raises(LookupError):
+        wordfreq_rs.word_frequency("las", "xx")
+    with pytest.raises(LookupError):
+        wordfreq_rs.word_frequency("las", "en", wordlist="medium")
+
+
+def _python_wordfreq():
+    wordfreq = pytest.importorskip("wordfreq")
+    try:
+        wordfreq_rs.word_frequency("the", "en")
+    except LookupError:
+        pytest.skip("No English model is enabled.")
+    return wordfreq
+
+
+@pytest.mark.parametrize(
+    "word", ["the", "cafe", "café", "Las Vegas", "2,000 dollars", "rustacean", ""]
+)
+def test_parity_word_frequency(word):
+    wordfreq = _python_wordfreq()
+    expected = wordfreq.word_frequency(word, "en")
+    assert wordfreq_rs.word_frequency(word, "en") == pytest.approx(expected, rel=1e-2)
+    expected = wordfreq.zipf_frequency(word, "en", minimum=1.0)
+    assert wordfreq_rs.zipf_frequency(word, "en", minimum=1.0) == pytest.approx(expected, abs=0.011)
+
+
+def test_parity_top_n_list():
+    wordfreq = _python_wordfreq()
+    # The order of words with the same frequency can differ.
+    expected = set(wordfreq.top_n_list("en", 100, ascii_only=True)[:50])
+    actual = set(wordfreq_rs.top_n_list("en", 100, ascii_only=True))
+    assert expected <= actual
@@ -0,0 +1,78 @@
+//! # wordfreq-wasm
+//!
+//! WebAssembly bindings of [wordfreq] with [wasm-bindgen](https://github.com/rustwasm/wasm-bindgen),
+//! to look up word frequencies in browsers and Node.js.
+//!
+//! ```js
+//! import init, { WordFreq } from "./pkg/wordfreq_wasm.js";
+//!
+//! await init();
+//! const buffer = await (await fetch("large_en.bin")).arrayBuffer();
+//! const wf = WordFreq.fromBytes(buffer, "en");
+//! console.log(wf.wordFrequency("cafe"), wf.zipfFrequency("cafe"));
+//! wf.free();
+//! ```
+//!
+//! The model is a buffer serialized by [`wordfreq::WordFreq::serialize()`],
+//! such as the files used by wordfreq-model.
+use js_sys::{ArrayBuffer, Uint8Array};
+use wasm_bindgen::prelude::*;
+use wordfreq::{Float, Standardizer};
+
+/// Word frequency model, exposed to JavaScript as `WordFreq`.
+#[wasm_bindgen]
+pub struct WordFreq(wordfreq::WordFreq);
+
+#[wasm_bindgen]
+impl WordFreq {
+    /// Creates an instance from an `ArrayBuffer` serialized by `WordFreq::serialize()` in Rust.
+    ///
+    /// If `lang` is given, words are standardized for the language before looking them up.
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(buffer: &ArrayBuffer, lang: Option<String>) -> Result<Self, JsError> {
+        let bytes = Uint8Array::new(buffer).to_vec();
+        let wf = wordfreq::WordFreq::deserialize(&bytes).map_err(to_js_error)?;
+        with_standardizer(wf, lang)
+    }
+
+    /// Creates an instance from lines of `<word> <weight>`.
+    ///
+    /// If `lang` is given, words are standardized for the language before looking them up.
+    #[wasm_bindgen(js_name = fromText)]
+    pub fn from_text(text: &str, lang: Option<String>) -> Result<Self, JsError> {
+        let word_weights =
+            wordfreq::word_weights_from_text(text.as_bytes()).map_err(to_js_error)?;
+        with_standardizer(wordfreq::WordFreq::new(word_weights), lang)
+    }
+
+    /// Returns the frequency of the word, between 0 and 1.
+    #[wasm_bindgen(js_name = wordFrequency)]
+    pub fn word_frequency(&self, word: &str) -> Float {
+        self.0.word_frequency(word)
+    }
+
+    /// Returns the Zipf frequency of the word.
+    #[wasm_bindgen(js_name = zipfFrequency)]
+    pub fn zipf_frequency(&self, word: &str) -> Float {
+        self.0.zipf_frequency(word)
+    }
+}
+
+/// Standardizes the word for the language, as done before looking it up.
+#[wasm_bindgen]
+pub fn standardize(lang: &str, word: &str) -> Result<String, JsError> {
+    let standardizer = Standardizer::new(lang).map_err(to_js_error)?;
+    Ok(standardizer.apply(word))
+}
+
+fn with_standardizer(wf: wordfreq::WordFreq, lang: Option<String>) -> Result<WordFreq, JsError> {
+    let wf = match lang {
+        Some(lang) => wf.standardizer(Standardizer::new(&lang).map_err(to_js_error)?),
+        None => wf,
+    };
+    Ok(WordFreq(wf))
+}
+
+fn to_js_error(e: anyhow::Error) -> JsError {
+    JsError::new(&e.to_string())
+}
@@ -0,0 +1,43 @@
+// Run with `cargo test --target wasm32-unknown-unknown` in the crate directory.
+#![cfg(target_arch = "wasm32")]
+
+use js_sys::Uint8Array;
+use wasm_bindgen_test::*;
+use wordfreq_wasm::{standardize, WordFreq};
+
+fn example_buffer() -> js_sys::ArrayBuffer {
+    let bytes = wordfreq::WordFreq::new([("las", 10.), ("vegas", 30.)])
+        .serialize()
+        .unwrap();
+    Uint8Array::from(bytes.as_slice()).buffer()
+}
+
+#[wasm_bindgen_test]
+fn test_from_bytes() {
+    let wf = WordFreq::from_bytes(&example_buffer(), None).unwrap();
+    assert_eq!(wf.word_frequency("las"), 0.25);
+    assert_eq!(wf.word_frequency("Las"), 0.);
+    assert_eq!(wf.zipf_frequency("vegas"), 8.88);
+
+    let wf = WordFreq::from_bytes(&example_buffer(), Some("en".to_string())).unwrap();
+    assert_eq!(wf.word_frequency("Las"), 0.25);
+}
+
+#[wasm_bindgen_test]
+fn test_from_text() {
+    let wf = WordFreq::from_text("las 10\nvegas 30\n", None).unwrap();
+    assert_eq!(wf.word_frequency("vegas"), 0.75);
+    assert!(WordFreq::from_text("las\n", None).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_invalid() {
+    let buffer = Uint8Array::from([0xffu8, 0xff].as_slice()).buffer();
+    assert!(WordFreq::from_bytes(&buffer, None).is_err());
+    assert!(WordFreq::from_bytes(&example_buffer(), Some("xx".to_string())).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_standardize() {
+    assert_eq!(standardize("tr", "İstanbul").unwrap(), "istanbul");
+}
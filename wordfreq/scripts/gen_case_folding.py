@@ -0,0 +1,41 @@
+# Generates CASE_FOLDING_TABLE in src/casefold.rs from CaseFolding.txt of Unicode.
+#
+# Usage:
+#   wget https://www.unicode.org/Public/16.0.0/ucd/CaseFolding.txt
+#   python gen_case_folding.py CaseFolding.txt
+import re
+import sys
+
+
+def escape(c):
+    if c == 0:
+        return r"'\0'"
+    if 0x20 < c < 0x7F and chr(c) not in "'\\":
+        return f"'{chr(c)}'"
+    return f"'\\u{{{c:x}}}'"
+
+
+path = sys.argv[1] if len(sys.argv) > 1 else 'CaseFolding.txt'
+with open(path, encoding='utf-8') as f:
+    header = f.readline()
+    version = re.search(r'CaseFolding-([\d.]+)\.txt', header).group(1)
+    mappings = []
+    for line in f:
+        line = line.split('#', 1)[0].strip()
+        if not line:
+            continue
+        code, status, mapping = [field.strip() for field in line.split(';')[:3]]
+        # The statuses S and T are for simple and Turkic foldings.
+        if status not in ('C', 'F'):
+            continue
+        folded = [int(x, 16) for x in mapping.split()]
+        assert len(folded) <= 3
+        mappings.append((int(code, 16), folded + [0] * (3 - len(folded))))
+mappings.sort()
+
+print(f'// Generated by scripts/gen_case_folding.py from CaseFolding.txt of Unicode {version},')
+print('// with the statuses C and F.')
+print('const CASE_FOLDING_TABLE: &[(char, [char; 3])] = &[')
+for code, folded in mappings:
+    print(f"    ({escape(code)}, [{', '.join(escape(c) for c in folded)}]),")
+print('];')
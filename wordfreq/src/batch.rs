@@ -0,0 +1,231 @@
+// Copyright 2023 Shunsuke Kanda
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use hashbrown::HashMap;
+
+use crate::{Float, WordFreq};
+
+// Maximum number of distinct words kept by a batch lookup without the shared cache.
+const BATCH_CACHE_CAPACITY: usize = 1 << 16;
+
+impl WordFreq {
+    /// Returns the frequencies of the words, in the same order.
+    ///
+    /// If the cache is enabled with `WordFreq::cache()`, the lookups share it.
+    /// Otherwise, each distinct word is standardized and looked up only once,
+    /// keeping up to 65,536 distinct words at a time,
+    /// so it is faster than calling [`WordFreq::word_frequency()`] for each word
+    /// when words are repeated, as in running text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordfreq::WordFreq;
+    ///
+    /// let wf = WordFreq::new([("las", 10.), ("vegas", 30.)]);
+    /// let freqs = wf.word_frequencies(&["las", "vegas", "las", "nevada"]);
+    /// assert_eq!(freqs, vec![0.25, 0.75, 0.25, 0.]);
+    /// ```
+    pub fn word_frequencies<W>(&self, words: &[W]) -> Vec<Float>
+    where
+        W: AsRef<str>,
+    {
+        let mut cache = BatchCache::new(self);
+        words
+            .iter()
+            .map(|word| cache.word_frequency(self, word.as_ref()))
+            .collect()
+    }
+
+    /// Returns an iterator of the frequencies of the words, which caches them
+    /// in the same manner as [`WordFreq::word_frequencies()`].
+    ///
+    /// The memory for the distinct words is bounded, even on long streams of words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordfreq::WordFreq;
+    ///
+    /// let wf = WordFreq::new([("las", 10.), ("vegas", 30.)]);
+    /// let text = "las vegas las";
+    /// let sum: f32 = wf.word_frequencies_iter(text.split(' ')).sum();
+    /// assert_eq!(sum, 1.25);
+    /// ```
+    pub fn word_frequencies_iter<I>(&self, words: I) -> WordFrequencies<'_, I::IntoIter>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        WordFrequencies {
+            wf: self,
+            words: words.into_iter(),
+            cache: BatchCache::new(self),
+        }
+    }
+
+    /// Checks if the shared cache is enabled with `WordFreq::cache()`.
+    const fn has_cache(&self) -> bool {
+        #[cfg(feature = "std")]
+        {
+            self.cache.is_some()
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            false
+        }
+    }
+
+    /// Returns the frequencies of the words in parallel, in the same order.
+    ///
+    /// Each distinct word is standardized and looked up only once, as in
+    /// [`WordFreq::word_frequencies()`], and the distinct words are processed in parallel
+    /// with [rayon](https://docs.rs/rayon).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordfreq::WordFreq;
+    ///
+    /// let wf = WordFreq::new([("las", 10.), ("vegas", 30.)]);
+    /// let freqs = wf.par_word_frequencies(&["las", "vegas", "las", "nevada"]);
+    /// assert_eq!(freqs, vec![0.25, 0.75, 0.25, 0.]);
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_word_frequencies<W>(&self, words: &[W]) -> Vec<Float>
+    where
+        W: AsRef<str> + Sync,
+    {
+        use rayon::prelude::*;
+
+        // Assigns an ID to each distinct word.
+        let mut ids = HashMap::new();
+        let mut distinct = vec![];
+        let word_ids: Vec<_> = words
+            .iter()
+            .map(|word| {
+                let word = word.as_ref();
+                *ids.entry(word).or_insert_with(|| {
+                    distinct.push(word);
+                    distinct.len() - 1
+                })
+            })
+            .collect();
+
+        let freqs: Vec<_> = distinct
+            .par_iter()
+            .map(|word| self.word_frequency(word))
+            .collect();
+        word_ids.into_iter().map(|id| freqs[id]).collect()
+    }
+}
+
+/// Frequencies of the distinct words in a batch, used if the shared cache is disabled.
+///
+/// It is cleared when full, to bound the memory.
+struct BatchCache {
+    map: Option<HashMap<String, Float>>,
+}
+
+impl BatchCache {
+    fn new(wf: &WordFreq) -> Self {
+        Self {
+            map: (!wf.has_cache()).then(HashMap::new),
+        }
+    }
+
+    fn word_frequency(&mut self, wf: &WordFreq, word: &str) -> Float {
+        let Some(map) = self.map.as_mut() else {
+            return wf.word_frequency(word);
+        };
+        if let Some(&freq) = map.get(word) {
+            return freq;
+        }
+        let freq = wf.word_frequency(word);
+        if map.len() >= BATCH_CACHE_CAPACITY {
+            map.clear();
+        }
+        map.insert(word.to_string(), freq);
+        freq
+    }
+}
+
+/// Iterator of word frequencies created by [`WordFreq::word_frequencies_iter()`].
+pub struct WordFrequencies<'a, I> {
+    wf: &'a WordFreq,
+    words: I,
+    cache: BatchCache,
+}
+
+impl<I> Iterator for WordFrequencies<'_, I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = Float;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let word = self.words.next()?;
+        Some(self.cache.word_frequency(self.wf, word.as_ref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.words.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Standardizer;
+
+    fn example() -> WordFreq {
+        WordFreq::new([("las", 10.), ("vegas", 30.), ("0000", 10.)])
+            .standardizer(Standardizer::new("en").unwrap())
+    }
+
+    #[test]
+    fn test_word_frequencies() {
+        let wf = example();
+        let words = ["Las", "VEGAS", "las", "2023", "1999", "2023", "nevada"];
+        let expected: Vec<_> = words.iter().map(|w| wf.word_frequency(w)).collect();
+        assert_eq!(wf.word_frequencies(&words), expected);
+        assert_eq!(
+            wf.word_frequencies_iter(words.iter().map(|w| w.to_string()))
+                .collect::<Vec<_>>(),
+            expected
+        );
+        assert!(wf.word_frequencies::<&str>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_word_frequencies_iter_bounded() {
+        let wf = example();
+        let mut iter =
+            wf.word_frequencies_iter((0..BATCH_CACHE_CAPACITY * 2).map(|i| format!("w{i}")));
+        for _ in 0..BATCH_CACHE_CAPACITY + 10 {
+            assert_eq!(iter.next(), Some(0.));
+        }
+        assert_eq!(iter.cache.map.as_ref().unwrap().len(), 10);
+    }
+
+    #[test]
+    fn test_word_frequencies_shared_cache() {
+        let wf = example().cache(10).unwrap();
+        let words = ["Las", "VEGAS", "Las", "Las"];
+        assert_eq!(wf.word_frequencies(&words), vec![0.2, 0.6, 0.2, 0.2]);
+        let stats = wf.cache_stats().unwrap();
+        assert_eq!((stats.hits, stats.misses), (2, 2));
+        assert!(wf.word_frequencies_iter(words).cache.map.is_none());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_word_frequencies() {
+        let wf = example();
+        let words: Vec<_> = (0..10000).map(|i| format!("{}", i % 3000)).collect();
+        assert_eq!(wf.par_word_frequencies(&words), wf.word_frequencies(&words));
+    }
+}
@@ -0,0 +1,244 @@
+// Copyright 2023 Shunsuke Kanda
+//! Building models from raw corpora.
+use std::io::{BufRead, Write};
+
+use anyhow::Result;
+use hashbrown::HashMap;
+
+use crate::numbers::NumberHandler;
+use crate::{Float, Standardizer, Tokenizer, WordFreq};
+
+/// Builder of word frequencies from documents, with the same semantics as the distributed models.
+///
+/// Each document is split into tokens with [`Tokenizer`], which are standardized with
+/// [`Standardizer::apply()`] and whose digit sequences are replaced by zeroes
+/// (such as `"2023"` to `"0000"`), in the same manner as [`WordFreq`] looks up words.
+/// Words occurring less than the minimum count are dropped.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use approx::assert_relative_eq;
+/// use wordfreq::WordFreqBuilder;
+///
+/// let mut builder = WordFreqBuilder::new("en")?.min_count(2);
+/// builder.add_document("Las Vegas is in Nevada.");
+/// builder.add_document("I went to Las Vegas in 2019.");
+/// builder.add_document("LAS VEGAS, 2023");
+///
+/// assert_eq!(
+///     builder.word_counts(),
+///     vec![
+///         ("las".to_string(), 3),
+///         ("vegas".to_string(), 3),
+///         ("0000".to_string(), 2),
+///         ("in".to_string(), 2),
+///     ]
+/// );
+///
+/// let wf = builder.build();
+/// assert_relative_eq!(wf.word_frequency("Las"), 0.3);
+/// assert_relative_eq!(wf.word_frequency("is"), 0.0);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The counts can be written in the text format read by [`word_weights_from_text()`](crate::word_weights_from_text),
+/// or compressed into a `.txt.zst` file like the distributed models with the `zstd` feature.
+#[derive(Clone)]
+pub struct WordFreqBuilder {
+    tokenizer: Tokenizer,
+    standardizer: Standardizer,
+    num_handler: NumberHandler,
+    counts: HashMap<String, u64>,
+    min_count: u64,
+}
+
+impl WordFreqBuilder {
+    /// Creates a builder for the given language.
+    ///
+    /// # Arguments
+    ///
+    /// - `language_tag`: Language tag, which should be one of left keys in [`crate::language::LIKELY_SUBTAGS`].
+    pub fn new(language_tag: &str) -> Result<Self> {
+        Ok(Self {
+            tokenizer: Tokenizer::new(language_tag)?,
+            standardizer: Standardizer::new(language_tag)?,
+            num_handler: NumberHandler::new(),
+            counts: HashMap::new(),
+            min_count: 1,
+        })
+    }
+
+    /// Sets the tokenizer, such as one with a dictionary for segmenting Chinese.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn tokenizer(mut self, tokenizer: Tokenizer) -> Self {
+        self.tokenizer = tokenizer;
+        self
+    }
+
+    /// Sets the standardizer.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn standardizer(mut self, standardizer: Standardizer) -> Self {
+        self.standardizer = standardizer;
+        self
+    }
+
+    /// Sets the minimum count of words to keep (default is 1).
+    pub const fn min_count(mut self, min_count: u64) -> Self {
+        self.min_count = min_count;
+        self
+    }
+
+    /// Counts the words in the document.
+    pub fn add_document(&mut self, text: &str) {
+        for token in self.tokenizer.tokenize(text) {
+            let word = self.standardizer.apply(&token);
+            // Words with spaces cannot be written in the text format.
+            if word.is_empty() || word.chars().any(char::is_whitespace) {
+                continue;
+            }
+            let word = self.num_handler.smash_numbers(&word);
+            *self.counts.entry(word).or_insert(0) += 1;
+        }
+    }
+
+    /// Counts the words in the documents.
+    pub fn add_documents<I, S>(&mut self, documents: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for document in documents {
+            self.add_document(document.as_ref());
+        }
+    }
+
+    /// Counts the words in the reader, regarding each line as a document.
+    pub fn add_reader<R: BufRead>(&mut self, rdr: R) -> Result<()> {
+        for line in rdr.lines() {
+            self.add_document(&line?);
+        }
+        Ok(())
+    }
+
+    /// Returns pairs of words and their counts, excluding those below the minimum count,
+    /// in descending order of counts (and in lexicographical order for ties).
+    pub fn word_counts(&self) -> Vec<(String, u64)> {
+        let mut word_counts: Vec<_> = self
+            .counts
+            .iter()
+            .filter(|(_, &count)| count >= self.min_count)
+            .map(|(word, &count)| (word.clone(), count))
+            .collect();
+        word_counts.sort_unstable_by(|(w1, c1), (w2, c2)| c2.cmp(c1).then_with(|| w1.cmp(w2)));
+        word_counts
+    }
+
+    /// Builds a [`WordFreq`] instance with the standardizer.
+    pub fn build(&self) -> WordFreq {
+        let word_weights = self
+            .word_counts()
+            .into_iter()
+            .map(|(word, count)| (word, count as Float));
+        WordFreq::new(word_weights).standardizer(self.standardizer.clone())
+    }
+
+    /// Writes the word counts in the text format read by
+    /// [`word_weights_from_text()`](crate::word_weights_from_text).
+    pub fn write_text<W: Write>(&self, mut wtr: W) -> Result<()> {
+        for (word, count) in self.word_counts() {
+            writeln!(wtr, "{word} {count}")?;
+        }
+        wtr.flush()?;
+        Ok(())
+    }
+
+    /// Writes the word counts in the text format compressed with Zstandard,
+    /// the same format as the `.txt.zst` files of the distributed models.
+    ///
+    /// # Arguments
+    ///
+    /// - `level`: Compression level of Zstandard (`0` uses the default level).
+    #[cfg(feature = "zstd")]
+    pub fn write_zst<W: Write>(&self, wtr: W, level: i32) -> Result<()> {
+        let mut encoder = zstd::Encoder::new(wtr, level)?;
+        self.write_text(&mut encoder)?;
+        encoder.finish()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    use crate::word_weights_from_text;
+
+    #[test]
+    fn test_word_counts() {
+        let mut builder = WordFreqBuilder::new("tr").unwrap();
+        builder.add_documents(["İstanbul 1453", "istanbul'da 2,000 kişi"]);
+        assert_eq!(
+            builder.word_counts(),
+            vec![
+                ("0,000".to_string(), 1),
+                ("0000".to_string(), 1),
+                ("istanbul".to_string(), 1),
+                ("istanbul'da".to_string(), 1),
+                ("kişi".to_string(), 1),
+            ]
+        );
+        let builder = builder.min_count(2);
+        assert!(builder.word_counts().is_empty());
+    }
+
+    #[test]
+    fn test_add_reader() {
+        let mut builder = WordFreqBuilder::new("en").unwrap();
+        builder.add_reader("a b\nb c\n\nc".as_bytes()).unwrap();
+        assert_eq!(
+            builder.word_counts(),
+            vec![
+                ("b".to_string(), 2),
+                ("c".to_string(), 2),
+                ("a".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_write_text() {
+        let mut builder = WordFreqBuilder::new("en").unwrap();
+        builder.add_document("Las Vegas, las vegas, VEGAS in 1999");
+        let mut text = vec![];
+        builder.write_text(&mut text).unwrap();
+        assert_eq!(
+            String::from_utf8(text.clone()).unwrap(),
+            "vegas 3\nlas 2\n0000 1\nin 1\n"
+        );
+
+        let word_weights = word_weights_from_text(text.as_slice()).unwrap();
+        let wf = WordFreq::new(word_weights).standardizer(Standardizer::new("en").unwrap());
+        let built = builder.build();
+        for word in ["Las", "VEGAS", "in", "1999", "2020"] {
+            assert_relative_eq!(wf.word_frequency(word), built.word_frequency(word));
+        }
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_write_zst() {
+        let mut builder = WordFreqBuilder::new("en").unwrap();
+        builder.add_document("Las Vegas, las vegas, VEGAS in 1999");
+        let mut compressed = vec![];
+        builder.write_zst(&mut compressed, 0).unwrap();
+        let decoder = zstd::Decoder::new(compressed.as_slice()).unwrap();
+        let word_weights = word_weights_from_text(std::io::BufReader::new(decoder)).unwrap();
+        assert_eq!(word_weights.len(), 4);
+        assert_eq!(word_weights[0], ("vegas".to_string(), 3.));
+    }
+}
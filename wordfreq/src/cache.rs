@@ -0,0 +1,118 @@
+// Copyright 2023 Shunsuke Kanda
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use lru::LruCache;
+
+use crate::Float;
+
+/// Statistics of the lookup cache enabled with [`WordFreq::cache()`](crate::WordFreq::cache).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Number of lookups answered from the cache.
+    pub hits: u64,
+    /// Number of lookups not found in the cache.
+    pub misses: u64,
+    /// Number of words in the cache.
+    pub len: usize,
+    /// Maximum number of words in the cache.
+    pub capacity: usize,
+}
+
+/// Thread-safe LRU cache from raw words to their frequencies before applying the minimum.
+pub struct LookupCache {
+    entries: Mutex<LruCache<String, Option<Float>>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl LookupCache {
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the cached value of the word, or computes and caches it.
+    ///
+    /// The lock is released during the computation, so concurrent misses of the same word
+    /// may compute it twice, which is harmless.
+    pub fn get_or_insert_with<F>(&self, word: &str, f: F) -> Option<Float>
+    where
+        F: FnOnce() -> Option<Float>,
+    {
+        if let Some(&freq) = self.lock().get(word) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return freq;
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let freq = f();
+        self.lock().put(word.to_string(), freq);
+        freq
+    }
+
+    /// Removes all the entries and resets the counters.
+    pub fn clear(&self) {
+        self.lock().clear();
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let entries = self.lock();
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            len: entries.len(),
+            capacity: entries.cap().get(),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruCache<String, Option<Float>>> {
+        // The cache is always consistent, even if another thread panicked.
+        self.entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Clone for LookupCache {
+    /// Creates an empty cache with the same capacity, because the cloned model can be
+    /// configured differently.
+    fn clone(&self) -> Self {
+        Self::new(self.lock().cap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lru() {
+        let cache = LookupCache::new(NonZeroUsize::new(2).unwrap());
+        assert_eq!(cache.get_or_insert_with("a", || Some(1.)), Some(1.));
+        assert_eq!(cache.get_or_insert_with("b", || None), None);
+        assert_eq!(cache.get_or_insert_with("a", || unreachable!()), Some(1.));
+        // Evicts "b", the least recently used.
+        assert_eq!(cache.get_or_insert_with("c", || Some(3.)), Some(3.));
+        assert_eq!(cache.get_or_insert_with("b", || Some(2.)), Some(2.));
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 1,
+                misses: 4,
+                len: 2,
+                capacity: 2
+            }
+        );
+
+        cache.clear();
+        assert_eq!(cache.stats().len, 0);
+        assert_eq!(cache.stats().misses, 0);
+        assert_eq!(cache.clone().stats().capacity, 2);
+    }
+}
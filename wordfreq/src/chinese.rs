@@ -3,6 +3,8 @@
 //
 // The code is a port from https://github.com/rspeer/wordfreq/blob/v3.0.2/wordfreq/chinese.py
 // together with the comments, following the MIT-license.
+use alloc::string::String;
+
 use hashbrown::HashMap;
 
 /// This list is extracted from _chinese_mapping.msgpack.gz.
@@ -3306,12 +3308,13 @@ impl ChineseSimplifier {
     pub fn simplify(&self, text: &str) -> String {
         let mut result = String::with_capacity(text.len());
         for c in text.chars() {
-            if let Some(replacement) = self.table.get(&c).cloned() {
-                result.push(replacement);
-            } else {
-                result.push(c);
-            }
+            result.push(self.simplify_char(c));
         }
-        caseless::default_case_fold_str(&result)
+        crate::casefold::default_case_fold_str(&result)
+    }
+
+    /// Converts a single character to Simplified Chinese, leaving the others as is.
+    pub fn simplify_char(&self, c: char) -> char {
+        self.table.get(&c).cloned().unwrap_or(c)
     }
 }
@@ -0,0 +1,469 @@
+// Copyright 2023 Shunsuke Kanda
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use anyhow::{anyhow, Result};
+use hashbrown::HashMap;
+
+use crate::{math, Float, WordFreq};
+
+// Frequency of Zipf 0.0, that is, once per a billion words.
+const DEFAULT_FLOOR: Float = 1e-9;
+const DEFAULT_NUM_WORDS: usize = 20;
+
+/// Statistics comparing the word distributions of two models, created by [`ModelComparator`].
+///
+/// The first model to compare is called the target, and the second one the reference.
+/// Logarithms are taken to base 2, so the divergences and log ratios are in bits.
+/// Statistics that are undefined, such as correlations without shared words, are 0.0.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModelComparison {
+    /// Kullback-Leibler divergence of the target from the reference, `KL(target || reference)`,
+    /// computed with the frequencies smoothed by [`ModelComparator::floor()`].
+    pub kl_divergence: Float,
+    /// Jensen-Shannon divergence in the range `[0, 1]`.
+    pub js_divergence: Float,
+    /// Number of words in both the vocabularies.
+    pub num_shared_words: usize,
+    /// Overlap coefficient of the vocabularies, `|A ∩ B| / min(|A|, |B|)`.
+    pub overlap_coefficient: Float,
+    /// Jaccard coefficient of the vocabularies, `|A ∩ B| / |A ∪ B|`.
+    pub jaccard_coefficient: Float,
+    /// Sum of the smaller frequencies of each word, that is,
+    /// one minus the total variation distance.
+    pub weighted_overlap: Float,
+    /// Spearman's rank correlation of the frequencies of the shared words.
+    pub spearman: Float,
+    /// Kendall's tau-b of the frequencies of the shared words.
+    pub kendall: Float,
+    /// Words most over-represented in the target, with their log ratios of the smoothed
+    /// frequencies of the target to the reference, in descending order.
+    pub overrepresented: Vec<(String, Float)>,
+    /// Words most under-represented in the target, with their log ratios of the smoothed
+    /// frequencies of the target to the reference, in ascending order.
+    pub underrepresented: Vec<(String, Float)>,
+}
+
+/// Comparator of the word distributions of [`WordFreq`] models,
+/// to detect domain drift or to sanity-check newly built models.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use approx::assert_relative_eq;
+/// use wordfreq::{ModelComparator, WordFreq};
+///
+/// let reference = WordFreq::new([("las", 10.), ("vegas", 30.)]);
+/// let comparator = ModelComparator::new().num_words(1);
+///
+/// let comparison = comparator.compare(&reference, &reference);
+/// assert_relative_eq!(comparison.js_divergence, 0.);
+/// assert_relative_eq!(comparison.spearman, 1.);
+///
+/// let comparison = comparator.compare_counts([("las", 3.), ("palmas", 1.)], &reference);
+/// assert_eq!(comparison.num_shared_words, 1);
+/// assert_relative_eq!(comparison.weighted_overlap, 0.25);
+/// assert_eq!(comparison.overrepresented[0].0, "palmas");
+/// assert_eq!(comparison.underrepresented[0].0, "vegas");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ModelComparator {
+    floor: Float,
+    num_words: usize,
+}
+
+impl Default for ModelComparator {
+    fn default() -> Self {
+        Self {
+            floor: DEFAULT_FLOOR,
+            num_words: DEFAULT_NUM_WORDS,
+        }
+    }
+}
+
+impl ModelComparator {
+    /// Creates a comparator with the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the frequency assumed for words missing in a model (default is 1e-9, or Zipf 0.0).
+    ///
+    /// It smooths the frequencies for the KL divergence and the log ratios,
+    /// which are otherwise infinite for words missing in a model.
+    /// An error is returned if the input is not positive.
+    pub fn floor(mut self, floor: Float) -> Result<Self> {
+        if !(floor > 0. && floor.is_finite()) {
+            return Err(anyhow!("floor must be positive"));
+        }
+        self.floor = floor;
+        Ok(self)
+    }
+
+    /// Sets the number of over- and under-represented words to report (default is 20).
+    pub const fn num_words(mut self, num: usize) -> Self {
+        self.num_words = num;
+        self
+    }
+
+    /// Compares the target model against the reference model.
+    ///
+    /// Only the stored frequencies are compared;
+    /// the minimums and number handling of the models are not taken into account.
+    pub fn compare(&self, target: &WordFreq, reference: &WordFreq) -> ModelComparison {
+        let (p, q) = (&target.map, &reference.map);
+
+        let mut words: Vec<_> = p.keys().chain(q.keys()).collect();
+        words.sort_unstable();
+        words.dedup();
+
+        let mut freqs = Vec::with_capacity(words.len());
+        let (mut sum_p, mut sum_q) = (0., 0.);
+        for word in &words {
+            let fp = p.get(*word).copied().unwrap_or(0.);
+            let fq = q.get(*word).copied().unwrap_or(0.);
+            sum_p += fp.max(self.floor);
+            sum_q += fq.max(self.floor);
+            freqs.push((fp, fq));
+        }
+
+        let mut kl_divergence = 0.;
+        let mut js_divergence = 0.;
+        let mut weighted_overlap = 0.;
+        let mut log_ratios = Vec::with_capacity(words.len());
+        let (mut shared_p, mut shared_q) = (vec![], vec![]);
+        for (word, &(fp, fq)) in words.iter().zip(&freqs) {
+            let sp = fp.max(self.floor) / sum_p;
+            let sq = fq.max(self.floor) / sum_q;
+            let log_ratio = math::log2(sp / sq);
+            kl_divergence += sp * log_ratio;
+            log_ratios.push((*word, fp, fq, log_ratio));
+
+            let fm = (fp + fq) / 2.;
+            if fp > 0. {
+                js_divergence += fp * math::log2(fp / fm) / 2.;
+            }
+            if fq > 0. {
+                js_divergence += fq * math::log2(fq / fm) / 2.;
+            }
+            weighted_overlap += fp.min(fq);
+
+            if fp > 0. && fq > 0. {
+                shared_p.push(fp);
+                shared_q.push(fq);
+            }
+        }
+
+        let num_shared_words = shared_p.len();
+        let min_len = p.len().min(q.len());
+
+        log_ratios
+            .sort_unstable_by(|(w1, _, _, r1), (w2, _, _, r2)| r2.total_cmp(r1).then(w1.cmp(w2)));
+        let overrepresented = log_ratios
+            .iter()
+            .filter(|&&(_, fp, _, log_ratio)| fp > 0. && log_ratio > 0.)
+            .take(self.num_words)
+            .map(|&(word, _, _, log_ratio)| (word.to_string(), log_ratio))
+            .collect();
+        log_ratios
+            .sort_unstable_by(|(w1, _, _, r1), (w2, _, _, r2)| r1.total_cmp(r2).then(w1.cmp(w2)));
+        let underrepresented = log_ratios
+            .iter()
+            .filter(|&&(_, _, fq, log_ratio)| fq > 0. && log_ratio < 0.)
+            .take(self.num_words)
+            .map(|&(word, _, _, log_ratio)| (word.to_string(), log_ratio))
+            .collect();
+
+        ModelComparison {
+            kl_divergence: kl_divergence.max(0.),
+            js_divergence: js_divergence.clamp(0., 1.),
+            num_shared_words,
+            overlap_coefficient: ratio(num_shared_words, min_len),
+            jaccard_coefficient: ratio(num_shared_words, words.len()),
+            weighted_overlap,
+            spearman: pearson(&ranks(&shared_p), &ranks(&shared_q)),
+            kendall: kendall_tau_b(&shared_p, &shared_q),
+            overrepresented,
+            underrepresented,
+        }
+    }
+
+    /// Compares counts of words, such as those from your own text, against the reference model.
+    ///
+    /// The words are standardized by the standardizer of the reference model, if any,
+    /// and their digit sequences are replaced by zeroes, as the reference model stores them
+    /// (such as `"2023"` to `"0000"`).
+    /// The counts of the same resulting words are summed up.
+    ///
+    /// # Arguments
+    ///
+    /// - `word_counts`: Pairs of words and their counts (or weights).
+    /// - `reference`: Reference model.
+    pub fn compare_counts<I, W>(&self, word_counts: I, reference: &WordFreq) -> ModelComparison
+    where
+        I: IntoIterator<Item = (W, Float)>,
+        W: AsRef<str>,
+    {
+        let mut counts = HashMap::new();
+        for (word, count) in word_counts {
+            let word = word.as_ref();
+            let word = reference
+                .standardizer
+                .as_ref()
+                .map_or_else(|| word.to_string(), |standardizer| standardizer.apply(word));
+            let word = reference.num_handler.smash_numbers(&word);
+            if !word.is_empty() {
+                *counts.entry(word).or_insert(0.) += count;
+            }
+        }
+        self.compare(&WordFreq::new(counts), reference)
+    }
+}
+
+fn ratio(num: usize, den: usize) -> Float {
+    if den == 0 {
+        0.
+    } else {
+        num as Float / den as Float
+    }
+}
+
+/// Returns the 1-based ranks of the values, averaging the ranks of ties.
+fn ranks(values: &[Float]) -> Vec<Float> {
+    let mut order: Vec<_> = (0..values.len()).collect();
+    order.sort_unstable_by(|&i, &j| values[i].total_cmp(&values[j]));
+    let mut ranks = vec![0.; values.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && values[order[end]] == values[order[start]] {
+            end += 1;
+        }
+        let rank = (start + end + 1) as Float / 2.;
+        order[start..end].iter().for_each(|&i| ranks[i] = rank);
+        start = end;
+    }
+    ranks
+}
+
+fn pearson(xs: &[Float], ys: &[Float]) -> Float {
+    if xs.is_empty() {
+        return 0.;
+    }
+    let n = xs.len() as Float;
+    let mean_x = xs.iter().sum::<Float>() / n;
+    let mean_y = ys.iter().sum::<Float>() / n;
+    let (mut sxy, mut sxx, mut syy) = (0., 0., 0.);
+    for (&x, &y) in xs.iter().zip(ys) {
+        let (dx, dy) = (x - mean_x, y - mean_y);
+        sxy += dx * dy;
+        sxx += dx * dx;
+        syy += dy * dy;
+    }
+    if sxx == 0. || syy == 0. {
+        return 0.;
+    }
+    (sxy / (math::sqrt(sxx) * math::sqrt(syy))).clamp(-1., 1.)
+}
+
+/// Computes Kendall's tau-b in O(n log n) time with Knight's algorithm.
+fn kendall_tau_b(xs: &[Float], ys: &[Float]) -> Float {
+    let mut pairs: Vec<_> = xs.iter().copied().zip(ys.iter().copied()).collect();
+    pairs.sort_unstable_by(|(x1, y1), (x2, y2)| x1.total_cmp(x2).then(y1.total_cmp(y2)));
+
+    let num_pairs = tied_pairs(&pairs, |_, _| true);
+    let x_ties = tied_pairs(&pairs, |(x1, _), (x2, _)| x1 == x2);
+    let xy_ties = tied_pairs(&pairs, |a, b| a == b);
+    let mut ys: Vec<_> = pairs.iter().map(|&(_, y)| y).collect();
+    let swaps = count_inversions(&mut ys);
+    let y_ties = tied_pairs(&ys, |y1, y2| y1 == y2);
+
+    let den = math::sqrt((num_pairs - x_ties) as Float) * math::sqrt((num_pairs - y_ties) as Float);
+    if den == 0. {
+        return 0.;
+    }
+    let num = num_pairs as i64 - x_ties as i64 - y_ties as i64 + xy_ties as i64 - 2 * swaps as i64;
+    (num as Float / den).clamp(-1., 1.)
+}
+
+/// Returns the number of pairs in the runs of consecutive items equal under `eq`.
+fn tied_pairs<T, F>(items: &[T], eq: F) -> u64
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let mut num = 0;
+    let mut start = 0;
+    while start < items.len() {
+        let mut end = start + 1;
+        while end < items.len() && eq(&items[start], &items[end]) {
+            end += 1;
+        }
+        let len = (end - start) as u64;
+        num += len * (len - 1) / 2;
+        start = end;
+    }
+    num
+}
+
+/// Sorts the values with the bottom-up merge sort and returns the number of inversions.
+fn count_inversions(values: &mut [Float]) -> u64 {
+    let n = values.len();
+    let mut buf = values.to_vec();
+    let mut swaps = 0;
+    let mut width = 1;
+    while width < n {
+        for start in (0..n).step_by(2 * width) {
+            let mid = (start + width).min(n);
+            let end = (start + 2 * width).min(n);
+            let (mut i, mut j, mut k) = (start, mid, start);
+            while i < mid && j < end {
+                if values[j] < values[i] {
+                    buf[k] = values[j];
+                    j += 1;
+                    swaps += (mid - i) as u64;
+                } else {
+                    buf[k] = values[i];
+                    i += 1;
+                }
+                k += 1;
+            }
+            buf[k..k + mid - i].copy_from_slice(&values[i..mid]);
+            buf[k + mid - i..end].copy_from_slice(&values[j..end]);
+        }
+        values.copy_from_slice(&buf);
+        width *= 2;
+    }
+    swaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    use crate::Standardizer;
+
+    // O(n^2) definition of Kendall's tau-b.
+    fn naive_kendall_tau_b(xs: &[Float], ys: &[Float]) -> Float {
+        let (mut concordant, mut discordant, mut x_ties, mut y_ties) = (0, 0, 0, 0);
+        for i in 0..xs.len() {
+            for j in i + 1..xs.len() {
+                let (dx, dy) = (xs[i] - xs[j], ys[i] - ys[j]);
+                if dx == 0. && dy == 0. {
+                } else if dx == 0. {
+                    x_ties += 1;
+                } else if dy == 0. {
+                    y_ties += 1;
+                } else if (dx > 0.) == (dy > 0.) {
+                    concordant += 1;
+                } else {
+                    discordant += 1;
+                }
+            }
+        }
+        let n1 = (concordant + discordant + x_ties) as Float;
+        let n2 = (concordant + discordant + y_ties) as Float;
+        (concordant - discordant) as Float / (n1 * n2).sqrt()
+    }
+
+    #[test]
+    fn test_kendall_tau_b() {
+        let xs = [1., 2., 2., 3., 4., 4., 4., 5., 6., 7.];
+        let ys = [3., 1., 1., 2., 5., 4., 4., 7., 6., 5.];
+        assert_relative_eq!(kendall_tau_b(&xs, &ys), naive_kendall_tau_b(&xs, &ys));
+        assert_relative_eq!(kendall_tau_b(&xs, &xs), 1.);
+        let rev: Vec<_> = xs.iter().map(|x| -x).collect();
+        assert_relative_eq!(kendall_tau_b(&xs, &rev), -1.);
+        assert_eq!(kendall_tau_b(&[1.], &[1.]), 0.);
+    }
+
+    #[test]
+    fn test_ranks() {
+        assert_eq!(ranks(&[0.3, 0.1, 0.3, 0.2]), vec![3.5, 1., 3.5, 2.]);
+    }
+
+    #[test]
+    fn test_compare() {
+        let target = WordFreq::new([("a", 4.), ("b", 3.), ("c", 2.), ("d", 1.)]);
+        let reference = WordFreq::new([("a", 4.), ("b", 2.), ("c", 3.), ("e", 1.)]);
+        let comparison = ModelComparator::new()
+            .num_words(1)
+            .compare(&target, &reference);
+
+        assert_eq!(comparison.num_shared_words, 3);
+        assert_relative_eq!(comparison.overlap_coefficient, 0.75);
+        assert_relative_eq!(comparison.jaccard_coefficient, 0.6);
+        assert_relative_eq!(comparison.weighted_overlap, 0.8);
+        assert_relative_eq!(comparison.spearman, 0.5);
+        assert_relative_eq!(comparison.kendall, 1. / 3.);
+        // JSD = H(M) - (H(P) + H(Q)) / 2, where M = (0.4, 0.25, 0.25, 0.05, 0.05).
+        let entropy = |ps: &[Float]| -ps.iter().map(|p| p * p.log2()).sum::<Float>();
+        let expected = entropy(&[0.4, 0.25, 0.25, 0.05, 0.05]) - entropy(&[0.4, 0.3, 0.2, 0.1]);
+        assert_relative_eq!(comparison.js_divergence, expected, max_relative = 1e-5);
+        assert!(comparison.kl_divergence > 0.);
+        assert_eq!(comparison.overrepresented[0].0, "d");
+        assert_eq!(comparison.underrepresented[0].0, "e");
+        assert_relative_eq!(
+            comparison.overrepresented[0].1,
+            -comparison.underrepresented[0].1,
+            max_relative = 1e-5
+        );
+    }
+
+    #[test]
+    fn test_compare_identical() {
+        let wf = WordFreq::new([("a", 4.), ("b", 3.), ("c", 2.), ("d", 1.)]);
+        let comparison = ModelComparator::new().compare(&wf, &wf);
+        assert_relative_eq!(comparison.kl_divergence, 0., epsilon = 1e-6);
+        assert_relative_eq!(comparison.js_divergence, 0., epsilon = 1e-6);
+        assert_relative_eq!(comparison.weighted_overlap, 1., epsilon = 1e-6);
+        assert_eq!(comparison.jaccard_coefficient, 1.);
+        assert_relative_eq!(comparison.kendall, 1.);
+        assert!(comparison.overrepresented.is_empty());
+        assert!(comparison.underrepresented.is_empty());
+    }
+
+    #[test]
+    fn test_compare_disjoint() {
+        let target = WordFreq::new([("a", 1.)]);
+        let reference = WordFreq::new([("b", 1.)]);
+        let comparison = ModelComparator::new().compare(&target, &reference);
+        assert_relative_eq!(comparison.js_divergence, 1.);
+        assert_eq!(comparison.num_shared_words, 0);
+        assert_eq!(comparison.spearman, 0.);
+        assert_eq!(comparison.kendall, 0.);
+    }
+
+    #[test]
+    fn test_compare_counts() {
+        let reference = WordFreq::new([("las", 10.), ("vegas", 30.)])
+            .standardizer(Standardizer::new("en").unwrap());
+        let comparison = ModelComparator::new()
+            .compare_counts([("Las", 1.), ("las", 1.), ("VEGAS", 6.)], &reference);
+        assert_relative_eq!(comparison.js_divergence, 0., epsilon = 1e-6);
+        assert_eq!(comparison.num_shared_words, 2);
+    }
+
+    #[test]
+    fn test_compare_counts_numbers() {
+        let reference = WordFreq::new([("in", 10.), ("0000", 5.), ("00.0", 5.)]);
+        let comparison = ModelComparator::new().compare_counts(
+            [("in", 2.), ("2023", 0.5), ("1999", 0.5), ("12.5", 1.)],
+            &reference,
+        );
+        assert_relative_eq!(comparison.js_divergence, 0., epsilon = 1e-6);
+        assert_eq!(comparison.num_shared_words, 3);
+        assert_eq!(comparison.jaccard_coefficient, 1.);
+    }
+
+    #[test]
+    fn test_invalid_floor() {
+        assert!(ModelComparator::new().floor(0.).is_err());
+        assert!(ModelComparator::new().floor(Float::NAN).is_err());
+    }
+}
@@ -0,0 +1,310 @@
+// Copyright 2023 Shunsuke Kanda
+use anyhow::{anyhow, Result};
+use hashbrown::{HashMap, HashSet};
+
+use crate::{Float, Tokenizer, WordFreq};
+
+// Models are normalized per a billion words in the Zipf scale.
+const DEFAULT_REFERENCE_SIZE: Float = 1e9;
+
+/// Statistics of keyness to rank keywords in [`KeywordExtractor`].
+///
+/// In the following, a word occurs `a` times in the text of `n` tokens,
+/// and has the frequency `f` in the reference corpus of `r` tokens.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum KeywordStatistic {
+    /// Log-likelihood ratio G² of the counts in the text and the reference corpus,
+    /// with a negative sign for words under-represented in the text.
+    #[default]
+    LogLikelihood,
+    /// TF-IDF-like surprisal, `a * -log2(f)`.
+    Surprisal,
+    /// Z-score of the log-odds ratio weighted by an informative Dirichlet prior,
+    /// using the reference corpus as the prior (Monroe et al., 2008).
+    LogOdds,
+}
+
+/// Extractor of keywords from texts, using [`WordFreq`] as the reference corpus.
+///
+/// Texts are split into tokens by [`Tokenizer`] using the model as the dictionary,
+/// and the tokens are standardized by the standardizer of the model, if any.
+/// Stopwords and numbers are counted in the size of the text but are never ranked.
+/// Words missing in the model are regarded as occurring once in the reference corpus.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use wordfreq::{KeywordExtractor, KeywordStatistic, Standardizer, WordFreq};
+///
+/// let wf = WordFreq::new([("the", 50.), ("and", 30.), ("other", 10.), ("cat", 1.)])
+///     .standardizer(Standardizer::new("en")?);
+/// let extractor = KeywordExtractor::new(&wf, "en")?.statistic(KeywordStatistic::LogOdds);
+///
+/// let keywords = extractor.keywords("The cat and the other cat.", 2);
+/// assert_eq!(keywords.len(), 2);
+/// assert_eq!(keywords[0].0, "cat");
+/// # Ok(())
+/// # }
+/// ```
+pub struct KeywordExtractor<'a> {
+    wf: &'a WordFreq,
+    tokenizer: Tokenizer,
+    statistic: KeywordStatistic,
+    stopwords: HashSet<String>,
+    numbers: bool,
+    reference_size: Float,
+}
+
+impl<'a> KeywordExtractor<'a> {
+    /// Creates an extractor for the language with the default options.
+    ///
+    /// # Arguments
+    ///
+    /// - `wf`: Model of the language, used as the reference corpus.
+    /// - `language_tag`: Language tag for the tokenizer, as in [`Tokenizer::new()`].
+    pub fn new(wf: &'a WordFreq, language_tag: &str) -> Result<Self> {
+        let tokenizer = Tokenizer::new(language_tag)?.dictionary(wf);
+        Ok(Self {
+            wf,
+            tokenizer,
+            statistic: KeywordStatistic::default(),
+            stopwords: HashSet::new(),
+            numbers: false,
+            reference_size: DEFAULT_REFERENCE_SIZE,
+        })
+    }
+
+    /// Sets the statistic to rank keywords (default is [`KeywordStatistic::LogLikelihood`]).
+    pub const fn statistic(mut self, statistic: KeywordStatistic) -> Self {
+        self.statistic = statistic;
+        self
+    }
+
+    /// Sets the stopwords, which are standardized in the same way as the tokens.
+    pub fn stopwords<I, W>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = W>,
+        W: AsRef<str>,
+    {
+        self.stopwords = words
+            .into_iter()
+            .map(|word| self.standardize(word.as_ref()))
+            .collect();
+        self
+    }
+
+    /// Sets whether to rank tokens containing digits, such as years (default is false).
+    ///
+    /// If enabled, their reference frequencies are estimated in the same way as
+    /// [`WordFreq::word_frequency()`].
+    pub const fn numbers(mut self, numbers: bool) -> Self {
+        self.numbers = numbers;
+        self
+    }
+
+    /// Sets the number of tokens assumed for the reference corpus (default is 1e9).
+    ///
+    /// It is the prior strength for [`KeywordStatistic::LogOdds`].
+    /// An error is returned if the input is not positive.
+    pub fn reference_size(mut self, size: Float) -> Result<Self> {
+        if !(size > 0. && size.is_finite()) {
+            return Err(anyhow!("reference_size must be positive"));
+        }
+        self.reference_size = size;
+        Ok(self)
+    }
+
+    /// Returns the top-`n` keywords in the text with their scores, in descending order.
+    pub fn keywords(&self, text: &str, n: usize) -> Vec<(String, Float)> {
+        let mut num_tokens = 0;
+        let mut counts = HashMap::new();
+        for token in self.tokenizer.tokenize(text) {
+            num_tokens += 1;
+            if !self.numbers && token.chars().any(char::is_numeric) {
+                continue;
+            }
+            let word = self.standardize(&token);
+            if word.is_empty() || self.stopwords.contains(&word) {
+                continue;
+            }
+            counts.entry(word).or_insert((token, 0)).1 += 1;
+        }
+
+        let mut keywords: Vec<_> = counts
+            .into_iter()
+            .map(|(word, (token, count))| {
+                let freq = self
+                    .wf
+                    .word_frequency_in(&token)
+                    .unwrap_or(0.)
+                    .max(self.wf.minimum)
+                    .max(1. / self.reference_size);
+                let score = self.score(count as Float, num_tokens as Float, freq);
+                (word, score)
+            })
+            .collect();
+        keywords.sort_unstable_by(|(w1, s1), (w2, s2)| s2.total_cmp(s1).then(w1.cmp(w2)));
+        keywords.truncate(n);
+        keywords
+    }
+
+    fn standardize(&self, word: &str) -> String {
+        self.wf
+            .standardizer
+            .as_ref()
+            .map_or_else(|| word.to_string(), |standardizer| standardizer.apply(word))
+    }
+
+    /// Scores a word occurring `a` times in `n` tokens, with the reference frequency `freq`.
+    fn score(&self, a: Float, n: Float, freq: Float) -> Float {
+        // Computed in f64, since the reference size is much larger than the counts in the text.
+        let (a, n, freq) = (f64::from(a), f64::from(n), f64::from(freq));
+        let r = f64::from(self.reference_size);
+        let score = match self.statistic {
+            KeywordStatistic::LogLikelihood => {
+                let b = freq * r;
+                let e1 = n * (a + b) / (n + r);
+                let e2 = r * (a + b) / (n + r);
+                let g2 = 2. * a.mul_add((a / e1).ln(), b * (b / e2).ln());
+                if a / n < freq {
+                    -g2
+                } else {
+                    g2
+                }
+            }
+            KeywordStatistic::Surprisal => a * -freq.log2(),
+            KeywordStatistic::LogOdds => {
+                let alpha = freq * r;
+                // The prior count of the other words, which is bounded for a word
+                // taking the whole reference corpus, as in a single-word model.
+                let rest = (r - alpha).max(1.);
+                let delta = ((a + alpha) / (n - a + rest)).ln() - (alpha / rest).ln();
+                let variance = 1. / (a + alpha) + 1. / alpha;
+                delta / variance.sqrt()
+            }
+        };
+        score as Float
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Standardizer;
+
+    const TEXT: &str = "The cat sat on the mat. The cat ate 2 fish in 2023.";
+
+    fn example() -> WordFreq {
+        WordFreq::new([
+            ("the", 50.),
+            ("on", 20.),
+            ("in", 20.),
+            ("sat", 3.),
+            ("ate", 3.),
+            ("fish", 2.),
+            ("mat", 1.),
+            ("cat", 1.),
+        ])
+        .standardizer(Standardizer::new("en").unwrap())
+    }
+
+    fn words(keywords: &[(String, Float)]) -> Vec<&str> {
+        keywords.iter().map(|(word, _)| word.as_str()).collect()
+    }
+
+    #[test]
+    fn test_statistics() {
+        let wf = example();
+        for statistic in [
+            KeywordStatistic::LogLikelihood,
+            KeywordStatistic::Surprisal,
+            KeywordStatistic::LogOdds,
+        ] {
+            let extractor = KeywordExtractor::new(&wf, "en")
+                .unwrap()
+                .statistic(statistic);
+            let keywords = extractor.keywords(TEXT, 10);
+            assert_eq!(words(&keywords[..1]), vec!["cat"], "{statistic:?}");
+            if statistic != KeywordStatistic::Surprisal {
+                // The most under-represented word.
+                assert_eq!(words(&keywords[keywords.len() - 1..]), vec!["the"]);
+            }
+            assert!(keywords.windows(2).all(|w| w[0].1 >= w[1].1));
+        }
+    }
+
+    #[test]
+    fn test_log_likelihood_sign() {
+        let wf = example();
+        let extractor = KeywordExtractor::new(&wf, "en").unwrap();
+        let keywords: HashMap<_, _> = extractor.keywords(TEXT, 10).into_iter().collect();
+        assert!(keywords["cat"] > 0.);
+        assert!(keywords["the"] < 0.);
+    }
+
+    #[test]
+    fn test_stopwords() {
+        let wf = example();
+        let extractor = KeywordExtractor::new(&wf, "en")
+            .unwrap()
+            .stopwords(["CAT", "The"]);
+        let keywords = extractor.keywords(TEXT, 10);
+        assert!(!words(&keywords).contains(&"cat"));
+        assert!(!words(&keywords).contains(&"the"));
+
+        // Stopwords are still counted in the size of the text.
+        let all = KeywordExtractor::new(&wf, "en").unwrap().keywords(TEXT, 10);
+        let all: HashMap<_, _> = all.into_iter().collect();
+        for (word, score) in keywords {
+            assert_eq!(all[&word], score);
+        }
+    }
+
+    #[test]
+    fn test_numbers() {
+        let wf = example();
+        let extractor = KeywordExtractor::new(&wf, "en").unwrap();
+        let keywords = extractor.keywords(TEXT, 10);
+        assert!(!words(&keywords).contains(&"2"));
+        assert!(!words(&keywords).contains(&"2023"));
+
+        let keywords = extractor.numbers(true).keywords(TEXT, 10);
+        assert!(words(&keywords).contains(&"2"));
+        assert!(words(&keywords).contains(&"2023"));
+    }
+
+    #[test]
+    fn test_top_n() {
+        let wf = example();
+        let extractor = KeywordExtractor::new(&wf, "en").unwrap();
+        assert_eq!(extractor.keywords(TEXT, 3).len(), 3);
+        assert!(extractor.keywords(TEXT, 0).is_empty());
+        assert!(extractor.keywords("", 3).is_empty());
+    }
+
+    #[test]
+    fn test_single_word_model() {
+        let wf = WordFreq::new([("cat", 1.)]);
+        for statistic in [
+            KeywordStatistic::LogLikelihood,
+            KeywordStatistic::Surprisal,
+            KeywordStatistic::LogOdds,
+        ] {
+            let extractor = KeywordExtractor::new(&wf, "en")
+                .unwrap()
+                .statistic(statistic);
+            let keywords = extractor.keywords("cat cat dog", 2);
+            assert_eq!(words(&keywords), vec!["dog", "cat"], "{statistic:?}");
+            assert!(keywords.iter().all(|(_, score)| score.is_finite()));
+        }
+    }
+
+    #[test]
+    fn test_invalid_reference_size() {
+        let wf = example();
+        let extractor = KeywordExtractor::new(&wf, "en").unwrap();
+        assert!(extractor.reference_size(0.).is_err());
+    }
+}
@@ -8049,6 +8049,17 @@ pub(crate) fn maximize_subtag(language: &str) -> Option<&str> {
         .map(|idx| LIKELY_SUBTAGS[idx].1)
 }
 
+/// Splits a maximized subtag, such as `sr-Cyrl-RS`, into its primary language and script.
+///
+/// Every maximized subtag in [`LIKELY_SUBTAGS`] has the form of `language-Script-Region`,
+/// so this does not need a full parser of language tags.
+pub(crate) fn split_subtag(subtag: &str) -> (&str, &str) {
+    let mut parts = subtag.split('-');
+    let language = parts.next().unwrap_or_default();
+    let script = parts.next().unwrap_or_default();
+    (language, script)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,243 @@
+// Copyright 2023 Shunsuke Kanda
+use alloc::string::ToString;
+
+use anyhow::{anyhow, Result};
+
+use crate::{math, Float, WordFreq};
+
+/// Combination of a domain-specific model with a general model.
+///
+/// Words are looked up in both the models, each with its own standardizer,
+/// and their frequencies are combined in either of the two ways:
+///
+/// - **Interpolation (default):** `weight * domain + (1 - weight) * general`.
+/// - **Backoff:** `weight * domain` if the domain model knows the word, otherwise
+///   the general frequency scaled so that the frequencies of all words sum to 1.
+///
+/// It provides the same lookup methods as [`WordFreq`].
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use wordfreq::{LayeredWordFreq, WordFreq};
+///
+/// let domain = WordFreq::new([("rust", 3.), ("cargo", 1.)]);
+/// let general = WordFreq::new([("the", 3.), ("rust", 1.)]);
+///
+/// let wf = LayeredWordFreq::new(domain.clone(), general.clone()).weight(0.8).unwrap();
+/// assert_relative_eq!(wf.word_frequency("rust"), 0.8 * 0.75 + 0.2 * 0.25);
+/// assert_relative_eq!(wf.word_frequency("the"), 0.2 * 0.75);
+///
+/// let wf = wf.backoff(true);
+/// assert_relative_eq!(wf.word_frequency("rust"), 0.8 * 0.75);
+/// // The remaining 0.2 is given to the words unknown to the domain model.
+/// assert_relative_eq!(wf.word_frequency("the"), 0.2);
+/// ```
+#[derive(Clone)]
+pub struct LayeredWordFreq {
+    domain: WordFreq,
+    general: WordFreq,
+    weight: Float,
+    backoff: bool,
+    // Sum of the general frequencies of the words known to the domain model.
+    general_mass_in_domain: Float,
+    minimum: Float,
+}
+
+impl LayeredWordFreq {
+    /// Creates an instance from the domain-specific and general models.
+    pub fn new(domain: WordFreq, general: WordFreq) -> Self {
+        // The general words are standardized in the same way as queries to the domain model,
+        // so that the mass agrees with the words for which the domain model is used.
+        let general_mass_in_domain = general
+            .map
+            .iter()
+            .filter(|(word, _)| {
+                let word = domain
+                    .standardizer
+                    .as_ref()
+                    .map_or_else(|| word.to_string(), |standardizer| standardizer.apply(word));
+                // Looks up the whole class of digit sequences (such as "0000"),
+                // not a particular number.
+                domain
+                    .map
+                    .contains_key(&domain.num_handler.smash_numbers(&word))
+            })
+            .map(|(_, &freq)| freq)
+            .sum();
+        Self {
+            domain,
+            general,
+            weight: 0.5,
+            backoff: false,
+            general_mass_in_domain,
+            minimum: 0.,
+        }
+    }
+
+    /// Sets the weight of the domain-specific model (default is 0.5).
+    ///
+    /// An error is returned if the input is not in `[0, 1]`.
+    pub fn weight(mut self, weight: Float) -> Result<Self> {
+        if !(0. ..=1.).contains(&weight) {
+            return Err(anyhow!("weight must be in [0, 1]"));
+        }
+        self.weight = weight;
+        Ok(self)
+    }
+
+    /// Sets whether to back off to the general model only for words unknown to
+    /// the domain-specific model, instead of interpolating them (default is false).
+    pub const fn backoff(mut self, backoff: bool) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Sets the lower bound of returned frequencies (default is 0.0).
+    ///
+    /// An error is returned if the input is negative.
+    pub fn minimum(mut self, minimum: Float) -> Result<Self> {
+        if minimum < 0. {
+            return Err(anyhow!("minimum must be non-negative"));
+        }
+        self.minimum = minimum;
+        Ok(self)
+    }
+
+    /// Returns the word's frequency, normalized between 0.0 and 1.0.
+    pub fn word_frequency<W>(&self, word: W) -> Float
+    where
+        W: AsRef<str>,
+    {
+        self.word_frequency_in(word).unwrap_or(0.).max(self.minimum)
+    }
+
+    /// Returns the Zipf frequency of a word as a human-friendly logarithmic scale.
+    pub fn zipf_frequency<W>(&self, word: W) -> Float
+    where
+        W: AsRef<str>,
+    {
+        WordFreq::freq_to_rounded_zipf(self.word_frequency_in(word), self.minimum)
+    }
+
+    /// Returns the reference to the domain-specific model.
+    pub const fn domain(&self) -> &WordFreq {
+        &self.domain
+    }
+
+    /// Returns the reference to the general model.
+    pub const fn general(&self) -> &WordFreq {
+        &self.general
+    }
+
+    fn word_frequency_in<W>(&self, word: W) -> Option<Float>
+    where
+        W: AsRef<str>,
+    {
+        let word = word.as_ref();
+        let domain_freq = self.domain.word_frequency_in(word);
+        if self.backoff {
+            if let Some(freq) = domain_freq {
+                return Some(self.weight * freq);
+            }
+            let rest = 1. - self.general_mass_in_domain;
+            if rest <= 0. {
+                return None;
+            }
+            let scale = (1. - self.weight) / rest;
+            return self
+                .general
+                .word_frequency_in(word)
+                .map(|freq| scale * freq);
+        }
+        let general_freq = self.general.word_frequency_in(word);
+        if domain_freq.is_none() && general_freq.is_none() {
+            return None;
+        }
+        Some(math::mul_add(
+            self.weight,
+            domain_freq.unwrap_or(0.),
+            (1. - self.weight) * general_freq.unwrap_or(0.),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    use crate::Standardizer;
+
+    #[test]
+    fn test_interpolation() {
+        let domain = WordFreq::new([("a", 1.), ("b", 1.)]);
+        let general = WordFreq::new([("b", 1.), ("c", 3.)]);
+        let wf = LayeredWordFreq::new(domain, general).weight(0.6).unwrap();
+        assert_relative_eq!(wf.word_frequency("a"), 0.3);
+        assert_relative_eq!(wf.word_frequency("b"), 0.4);
+        assert_relative_eq!(wf.word_frequency("c"), 0.3);
+        assert_relative_eq!(wf.word_frequency("d"), 0.);
+    }
+
+    #[test]
+    fn test_backoff() {
+        let domain = WordFreq::new([("a", 1.), ("b", 1.)]);
+        let general = WordFreq::new([("b", 1.), ("c", 3.)]);
+        let wf = LayeredWordFreq::new(domain, general)
+            .weight(0.6)
+            .unwrap()
+            .backoff(true);
+        assert_relative_eq!(wf.word_frequency("a"), 0.3);
+        assert_relative_eq!(wf.word_frequency("b"), 0.3);
+        assert_relative_eq!(wf.word_frequency("c"), 0.4);
+        assert_relative_eq!(wf.word_frequency("d"), 0.);
+    }
+
+    #[test]
+    fn test_backoff_numbers() {
+        let domain = WordFreq::new([("0000", 1.)]);
+        let general = WordFreq::new([("0000", 1.), ("a", 1.)]);
+        let wf = LayeredWordFreq::new(domain, general)
+            .weight(0.5)
+            .unwrap()
+            .backoff(true);
+        assert_relative_eq!(wf.word_frequency("a"), 0.5);
+        assert!(wf.word_frequency("2019") > 0.);
+    }
+
+    #[test]
+    fn test_standardizers() {
+        let domain =
+            WordFreq::new([("istanbul", 1.)]).standardizer(Standardizer::new("tr").unwrap());
+        let general =
+            WordFreq::new([("istanbul", 1.)]).standardizer(Standardizer::new("en").unwrap());
+        let wf = LayeredWordFreq::new(domain, general);
+        assert_relative_eq!(wf.word_frequency("İstanbul"), 0.5);
+        assert_relative_eq!(wf.word_frequency("ISTANBUL"), 0.5);
+        assert_relative_eq!(wf.zipf_frequency("ISTANBUL"), 8.7);
+    }
+
+    #[test]
+    fn test_backoff_standardizers() {
+        // The general word "ISTANBUL" is known to the domain model as "ıstanbul".
+        let domain =
+            WordFreq::new([("ıstanbul", 1.)]).standardizer(Standardizer::new("tr").unwrap());
+        let general = WordFreq::new([("ISTANBUL", 1.), ("a", 1.)]);
+        let wf = LayeredWordFreq::new(domain, general)
+            .weight(0.5)
+            .unwrap()
+            .backoff(true);
+        assert_relative_eq!(wf.word_frequency("ISTANBUL"), 0.5);
+        assert_relative_eq!(wf.word_frequency("a"), 0.5);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let wf = LayeredWordFreq::new(WordFreq::new([("a", 1.)]), WordFreq::new([("a", 1.)]));
+        assert!(wf.clone().weight(1.1).is_err());
+        assert!(wf.minimum(-1.).is_err());
+    }
+}
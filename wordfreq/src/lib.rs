@@ -64,6 +64,76 @@
 //! # }
 //! ```
 //!
+//! With the `msgpack` feature, you can also create instances from the original word lists
+//! of Python's wordfreq, such as `large_en.msgpack.gz`, using `WordFreq::from_cb_msgpack()`,
+//! and export instances into the format with `WordFreq::to_cb_msgpack()`.
+//!
+//! ## Tokenization
+//!
+//! If you want to look up the frequencies of words in running text,
+//! split the text into tokens with [`Tokenizer`].
+//! For languages written without spaces, such as Chinese and Thai,
+//! the words and frequencies of a [`WordFreq`] instance can serve as the dictionary
+//! of a max-probability segmenter.
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use wordfreq::Tokenizer;
+//! use wordfreq::WordFreq;
+//!
+//! let wf = WordFreq::new([("北京", 10.), ("大学", 10.), ("北京大学", 1.), ("生", 2.)]);
+//! let tokenizer = Tokenizer::new("zh")?.dictionary(&wf);
+//! assert_eq!(tokenizer.tokenize("北京大学生"), vec!["北京", "大学", "生"]);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! For Japanese and Korean, enable the `lindera` feature to tokenize text in the same way as MeCab
+//! in Python's wordfreq. See [`Tokenizer`] for the details.
+//!
+//! ## Text profiling
+//!
+//! [`TextProfiler`] tokenizes a document and summarizes the Zipf frequencies of its words,
+//! such as the mean and median, the shares of tokens below thresholds, the rarest words,
+//! and the out-of-vocabulary rate, as a rough measure of vocabulary difficulty.
+//!
+//! [`KeywordExtractor`] ranks the words of a document as keywords by a statistic of keyness,
+//! such as the log-likelihood ratio, using a [`WordFreq`] model as the reference corpus.
+//!
+//! ## Building models
+//!
+//! [`WordFreqBuilder`] counts words in your own corpus to create models
+//! with the same semantics as the distributed ones.
+//! The counts can be written in the above text format, or into `.txt.zst` files
+//! with the `zstd` feature.
+//!
+//! [`ModelComparator`] compares the word distributions of two models,
+//! or of a model and word counts from your own text, with statistics such as
+//! divergences, vocabulary overlaps, rank correlations, and the most over- or under-represented words.
+//! It helps to detect domain drift and to sanity-check newly built models.
+//!
+//! ## WebAssembly
+//!
+//! This crate compiles to `wasm32-unknown-unknown` with the default and `msgpack` features,
+//! since it needs neither system calls nor native libraries to look up frequencies.
+//! [wordfreq-wasm](https://github.com/kampersanda/wordfreq-rs/tree/main/wordfreq-wasm)
+//! provides its JavaScript bindings.
+//!
+//! ## `no_std`
+//!
+//! The `std` feature is enabled by default. Without it, this crate depends only on `alloc`,
+//! so that it can be used in embedded and kernel targets:
+//!
+//! ```toml
+//! [dependencies]
+//! wordfreq = { version = "0.2", default-features = false }
+//! ```
+//!
+//! [`WordFreq`] and [`Standardizer`] are available, including [`WordFreq::deserialize()`]
+//! to load models embedded with `include_bytes!`, and the Zipf conversions are computed with
+//! [libm](https://docs.rs/libm).
+//! Reading text files, tokenization, caching, and the other optional features require `std`.
+//!
 //! ## Precision errors
 //!
 //! Even if the algorithms are the same, the results may differ slightly from the original implementation
@@ -74,22 +144,73 @@
 //! This crate is a straightforward port of Python's wordfreq,
 //! although some features are not provided:
 //!
-//! - [Tokenization](https://github.com/rspeer/wordfreq/tree/v3.0.2#tokenization)
+//! - [Tokenization](https://github.com/rspeer/wordfreq/tree/v3.0.2#tokenization) except for
+//!   the simple regex-based one, dictionary-based segmentation, and Japanese and Korean segmentation
+//!   (with the `lindera` feature) provided by [`Tokenizer`]
 //! - [Additional functions](https://github.com/rspeer/wordfreq/tree/v3.0.2#other-functions)
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 
+extern crate alloc;
+
+mod batch;
+#[cfg(feature = "std")]
+mod builder;
+#[cfg(feature = "std")]
+mod cache;
+mod casefold;
 mod chinese;
+mod divergence;
+#[cfg(feature = "std")]
+mod keywords;
 pub mod language;
-mod numbers;
+mod layered;
+mod math;
+mod merge;
+#[cfg(feature = "msgpack")]
+mod msgpack;
+pub mod numbers;
 pub mod preprocessers;
-mod transliterate;
+#[cfg(feature = "std")]
+mod profile;
+#[cfg(feature = "std")]
+mod reader;
+#[cfg(feature = "std")]
+mod segmenter;
+#[cfg(feature = "std")]
+pub mod tokenizers;
+pub mod transliterate;
 
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
 use std::io::BufRead;
+#[cfg(feature = "std")]
+use std::num::NonZeroUsize;
 
 use anyhow::{anyhow, Result};
 use hashbrown::HashMap;
 
+pub use batch::WordFrequencies;
+#[cfg(feature = "std")]
+pub use builder::WordFreqBuilder;
+#[cfg(feature = "std")]
+pub use cache::CacheStats;
+pub use divergence::{ModelComparator, ModelComparison};
+#[cfg(feature = "std")]
+pub use keywords::{KeywordExtractor, KeywordStatistic};
+pub use layered::LayeredWordFreq;
+pub use merge::MergeStrategy;
+#[cfg(feature = "msgpack")]
+pub use msgpack::word_weights_from_cb_msgpack;
+pub use numbers::NumberModel;
 pub use preprocessers::Standardizer;
+#[cfg(feature = "std")]
+pub use profile::{TextProfile, TextProfiler};
+#[cfg(feature = "std")]
+pub use reader::{WeightKind, WordWeightReader};
+#[cfg(feature = "std")]
+pub use tokenizers::Tokenizer;
 
 /// Common type of floating numbers.
 pub type Float = f32;
@@ -104,6 +225,8 @@ pub struct WordFreq {
     minimum: Float,
     num_handler: numbers::NumberHandler,
     standardizer: Option<Standardizer>,
+    #[cfg(feature = "std")]
+    cache: Option<cache::LookupCache>,
 }
 
 impl WordFreq {
@@ -132,6 +255,8 @@ impl WordFreq {
             minimum: 0.,
             num_handler: numbers::NumberHandler::new(),
             standardizer: None,
+            #[cfg(feature = "std")]
+            cache: None,
         }
     }
 
@@ -152,9 +277,94 @@ impl WordFreq {
     #[allow(clippy::missing_const_for_fn)]
     pub fn standardizer(mut self, standardizer: Standardizer) -> Self {
         self.standardizer = Some(standardizer);
+        #[cfg(feature = "std")]
+        self.clear_cache();
+        self
+    }
+
+    /// Sets the parameters used to estimate the frequencies of digit sequences.
+    ///
+    /// See [`NumberModel`] for the details.
+    pub fn number_model(mut self, model: NumberModel) -> Self {
+        self.num_handler = self.num_handler.model(model);
+        #[cfg(feature = "std")]
+        self.clear_cache();
+        self
+    }
+
+    /// Adds a numeric pattern used to estimate the frequencies of digit sequences,
+    /// such as [`numbers::Ordinals`] and [`numbers::Times`].
+    ///
+    /// See [`numbers::NumberHandler::pattern()`] for the details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordfreq::numbers::Times;
+    /// use wordfreq::WordFreq;
+    ///
+    /// let wf = WordFreq::new([("00:00", 1.)]).number_pattern(Times::new());
+    /// assert!(wf.word_frequency("12:30") > wf.word_frequency("12:31"));
+    /// ```
+    pub fn number_pattern<P>(mut self, pattern: P) -> Self
+    where
+        P: numbers::NumberPattern + 'static,
+    {
+        self.num_handler = self.num_handler.pattern(pattern);
+        #[cfg(feature = "std")]
+        self.clear_cache();
         self
     }
 
+    /// Enables the cache of looked-up words with the given capacity (default is disabled).
+    ///
+    /// The cache keeps the frequencies of the most recently looked-up words as they are input,
+    /// so repeated words skip the standardization and number handling.
+    /// It is thread-safe, and the least recently used word is evicted when it is full.
+    /// Use [`WordFreq::cache_stats()`] to see how effective it is.
+    ///
+    /// An error is returned if the capacity is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use wordfreq::{Standardizer, WordFreq};
+    ///
+    /// let wf = WordFreq::new([("las", 10.), ("vegas", 30.)])
+    ///     .standardizer(Standardizer::new("en")?)
+    ///     .cache(1000)?;
+    ///
+    /// for word in ["Las", "Vegas", "Las"] {
+    ///     wf.word_frequency(word);
+    /// }
+    /// let stats = wf.cache_stats().unwrap();
+    /// assert_eq!((stats.hits, stats.misses), (1, 2));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn cache(mut self, capacity: usize) -> Result<Self> {
+        let capacity =
+            NonZeroUsize::new(capacity).ok_or_else(|| anyhow!("capacity must be positive"))?;
+        self.cache = Some(cache::LookupCache::new(capacity));
+        Ok(self)
+    }
+
+    /// Returns the statistics of the cache, or [`None`] if the cache is disabled.
+    #[cfg(feature = "std")]
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.as_ref().map(cache::LookupCache::stats)
+    }
+
+    /// Removes all the words in the cache and resets its statistics, if enabled.
+    #[cfg(feature = "std")]
+    pub fn clear_cache(&self) {
+        if let Some(cache) = self.cache.as_ref() {
+            cache.clear();
+        }
+    }
+
     /// Returns the word's frequency, normalized between 0.0 and 1.0.
     ///
     /// # Examples
@@ -196,8 +406,14 @@ impl WordFreq {
     where
         W: AsRef<str>,
     {
-        let freq_min = Self::zipf_to_freq(self.minimum);
-        let freq = self.word_frequency_in(word).unwrap_or(0.).max(freq_min);
+        Self::freq_to_rounded_zipf(self.word_frequency_in(word), self.minimum)
+    }
+
+    /// Converts a frequency from [`WordFreq::word_frequency_in()`] into the Zipf frequency,
+    /// applying the minimum.
+    fn freq_to_rounded_zipf(freq: Option<Float>, minimum: Float) -> Float {
+        let freq_min = Self::zipf_to_freq(minimum);
+        let freq = freq.unwrap_or(0.).max(freq_min);
         let zipf = Self::freq_to_zipf(freq);
         Self::round(zipf, 2)
     }
@@ -206,10 +422,19 @@ impl WordFreq {
     where
         W: AsRef<str>,
     {
-        let word = self.standardizer.as_ref().map_or_else(
-            || word.as_ref().to_string(),
-            |standardizer| standardizer.apply(word.as_ref()),
-        );
+        let word = word.as_ref();
+        #[cfg(feature = "std")]
+        if let Some(cache) = self.cache.as_ref() {
+            return cache.get_or_insert_with(word, || self.lookup(word));
+        }
+        self.lookup(word)
+    }
+
+    fn lookup(&self, word: &str) -> Option<Float> {
+        let word = self
+            .standardizer
+            .as_ref()
+            .map_or_else(|| word.to_string(), |standardizer| standardizer.apply(word));
 
         let smashed = self.num_handler.smash_numbers(&word);
         let mut freq = self.map.get(&smashed).cloned()?;
@@ -232,26 +457,30 @@ impl WordFreq {
     }
 
     fn zipf_to_freq(zipf: Float) -> Float {
-        FLOAT_10.powf(zipf - 9.)
+        math::powf(FLOAT_10, zipf - 9.)
     }
 
     fn freq_to_zipf(freq: Float) -> Float {
-        freq.log10() + 9.
+        math::log10(freq) + 9.
     }
 
     fn round(x: Float, places: i32) -> Float {
-        let multiplier = FLOAT_10.powi(places);
-        (x * multiplier).round() / multiplier
+        let multiplier = math::powi(FLOAT_10, places);
+        math::round(x * multiplier) / multiplier
     }
 
     /// Exports the model data.
     ///
     /// Note that the format is distinct from the one used in the oritinal Python package.
     pub fn serialize(&self) -> Result<Vec<u8>> {
-        let mut bytes = vec![];
+        // Each entry is encoded in the same manner as bincode 1.x, i.e.,
+        // the length of the word as u64, the word in UTF-8, and the frequency as f32,
+        // all in little endian.
+        let mut bytes = Vec::new();
         for (k, v) in &self.map {
-            bincode::serialize_into(&mut bytes, k.as_bytes())?;
-            bincode::serialize_into(&mut bytes, v)?;
+            bytes.extend_from_slice(&(k.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(k.as_bytes());
+            bytes.extend_from_slice(&v.to_le_bytes());
         }
         Ok(bytes)
     }
@@ -260,8 +489,15 @@ impl WordFreq {
     pub fn deserialize(mut bytes: &[u8]) -> Result<Self> {
         let mut map = HashMap::new();
         while !bytes.is_empty() {
-            let k: String = bincode::deserialize_from(&mut bytes)?;
-            let v: Float = bincode::deserialize_from(&mut bytes)?;
+            let len = u64::from_le_bytes(take_bytes(&mut bytes)?);
+            let len = usize::try_from(len)?;
+            if bytes.len() < len {
+                return Err(anyhow!("The model data is truncated."));
+            }
+            let (k, rest) = bytes.split_at(len);
+            bytes = rest;
+            let k = core::str::from_utf8(k)?.to_string();
+            let v = Float::from_le_bytes(take_bytes(&mut bytes)?);
             map.insert(k, v);
         }
         Ok(Self {
@@ -269,13 +505,148 @@ impl WordFreq {
             minimum: 0.,
             num_handler: numbers::NumberHandler::new(),
             standardizer: None,
+            #[cfg(feature = "std")]
+            cache: None,
         })
     }
 
+    /// Creates an instance from a word list in the cB format of Python's wordfreq,
+    /// such as `large_en.msgpack.gz`.
+    ///
+    /// See [`word_weights_from_cb_msgpack()`] for the details of the format.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::fs::File;
+    ///
+    /// use wordfreq::{Standardizer, WordFreq};
+    ///
+    /// let wf = WordFreq::from_cb_msgpack(File::open("large_en.msgpack.gz")?)?
+    ///     .standardizer(Standardizer::new("en")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "msgpack")]
+    pub fn from_cb_msgpack<R: std::io::Read>(rdr: R) -> Result<Self> {
+        Ok(Self::new(word_weights_from_cb_msgpack(rdr)?))
+    }
+
+    /// Exports the model as a gzip-compressed word list in the cB format of Python's wordfreq,
+    /// which can be loaded with `wordfreq.read_cBpack` in Python.
+    ///
+    /// Frequencies are rounded to integer centibels, so the result is precise only to within 1.2%,
+    /// the same precision as the original word lists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use approx::assert_relative_eq;
+    /// use wordfreq::WordFreq;
+    ///
+    /// let wf = WordFreq::new([("las", 10.), ("vegas", 30.)]);
+    /// let mut bytes = vec![];
+    /// wf.to_cb_msgpack(&mut bytes)?;
+    ///
+    /// let other = WordFreq::from_cb_msgpack(bytes.as_slice())?;
+    /// assert_relative_eq!(other.word_frequency("las"), 0.25, max_relative = 0.03);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "msgpack")]
+    pub fn to_cb_msgpack<W: std::io::Write>(&self, wtr: W) -> Result<()> {
+        msgpack::write_cb_msgpack(self.map.iter().map(|(w, &f)| (w.as_str(), f)), wtr)
+    }
+
     /// Returns the reference to the internal word-frequency map.
     pub const fn word_frequency_map(&self) -> &HashMap<String, Float> {
         &self.map
     }
+
+    /// Merges word frequencies from multiple sources, such as a distributed model
+    /// and a model built from in-house counts.
+    ///
+    /// The frequencies of each word in the sources are merged by the strategy
+    /// with the weights of the sources, and then normalized to sum to 1.
+    /// The standardizer and the other settings are taken from the first source.
+    ///
+    /// # Arguments
+    ///
+    /// - `sources`: Pairs of models and their weights.
+    /// - `strategy`: Strategy of merging frequencies.
+    ///
+    /// An error is returned if `sources` is empty or a weight is not positive and finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use approx::assert_relative_eq;
+    /// use wordfreq::{MergeStrategy, WordFreq};
+    ///
+    /// let general = WordFreq::new([("las", 10.), ("vegas", 30.)]);
+    /// let domain = WordFreq::new([("las", 10.), ("palmas", 10.)]);
+    ///
+    /// let wf = WordFreq::merge(&[(&general, 1.), (&domain, 1.)], MergeStrategy::Mean)?;
+    /// assert_relative_eq!(wf.word_frequency("las"), 0.375);
+    /// assert_relative_eq!(wf.word_frequency("vegas"), 0.375);
+    /// assert_relative_eq!(wf.word_frequency("palmas"), 0.25);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn merge(sources: &[(&Self, Float)], strategy: MergeStrategy) -> Result<Self> {
+        let (first, _) = sources
+            .first()
+            .ok_or_else(|| anyhow!("sources must not be empty"))?;
+        if sources
+            .iter()
+            .any(|&(_, weight)| !(weight > 0. && weight.is_finite()))
+        {
+            return Err(anyhow!("weights must be positive and finite"));
+        }
+
+        let mut words: Vec<_> = sources.iter().flat_map(|(wf, _)| wf.map.keys()).collect();
+        words.sort_unstable();
+        words.dedup();
+
+        let mut freq_weights = Vec::with_capacity(sources.len());
+        let mut merged = HashMap::new();
+        for word in words {
+            freq_weights.clear();
+            freq_weights.extend(
+                sources
+                    .iter()
+                    .map(|&(wf, weight)| (wf.map.get(word).copied().unwrap_or(0.), weight)),
+            );
+            let freq = merge::merge_freqs(&mut freq_weights, strategy);
+            if freq > 0. {
+                merged.insert(word.clone(), freq);
+            }
+        }
+
+        let sum_freq = merged.values().fold(0., |acc, f| acc + f);
+        merged.values_mut().for_each(|f| *f /= sum_freq);
+        Ok(Self {
+            map: merged,
+            minimum: first.minimum,
+            num_handler: first.num_handler.clone(),
+            standardizer: first.standardizer.clone(),
+            #[cfg(feature = "std")]
+            cache: first.cache.clone(),
+        })
+    }
+}
+
+/// Consumes the first `N` bytes.
+fn take_bytes<const N: usize>(bytes: &mut &[u8]) -> Result<[u8; N]> {
+    if bytes.len() < N {
+        return Err(anyhow!("The model data is truncated."));
+    }
+    let (head, rest) = bytes.split_at(N);
+    *bytes = rest;
+    Ok(head.try_into().unwrap())
 }
 
 /// Parses pairs of a word and its weight from a text file,
@@ -285,6 +656,8 @@ impl WordFreq {
 /// <word> <weight>
 /// ```
 ///
+/// Use [`WordWeightReader`] for other formats, such as TSV and CSV files.
+///
 /// # Examples
 ///
 /// ```
@@ -299,14 +672,16 @@ impl WordFreq {
 /// # Ok(())
 /// # }
 /// ```
+#[cfg(feature = "std")]
 pub fn word_weights_from_text<R: BufRead>(rdr: R) -> Result<Vec<(String, Float)>> {
     let mut word_weights = vec![];
     for (i, line) in rdr.lines().enumerate() {
         let line = line?;
         let cols: Vec<_> = line.split_ascii_whitespace().collect();
         if cols.len() != 2 {
+            let line_no = i + 1;
             return Err(anyhow!(
-                "Line {i}: a line should be <word><SPACE><weight>, but got {line}."
+                "Line {line_no}: a line should be <word><SPACE><weight>, but got {line}."
             ));
         }
         word_weights.push((cols[0].to_string(), cols[1].parse()?));
@@ -328,6 +703,63 @@ mod tests {
         assert_relative_eq!(wf.word_frequency("vegas"), 0.00);
     }
 
+    #[test]
+    fn test_merge() {
+        let a = WordFreq::new([("a", 2.), ("b", 1.), ("c", 1.)]);
+        let b = WordFreq::new([("a", 1.), ("b", 1.)]);
+        let c = WordFreq::new([("a", 1.), ("c", 1.)]);
+        let sources = [(&a, 1.), (&b, 1.), (&c, 1.)];
+
+        let wf = WordFreq::merge(&sources, MergeStrategy::Median).unwrap();
+        assert_relative_eq!(wf.word_frequency("a"), 0.5);
+        assert_relative_eq!(wf.word_frequency("b"), 0.25);
+        assert_relative_eq!(wf.word_frequency("c"), 0.25);
+
+        // a: (0.5 + 0.5) / 2, b: (0.25 + 0.5) / 2, c: (0.25 + 0.5) / 2
+        let wf = WordFreq::merge(&sources, MergeStrategy::DropLowest).unwrap();
+        assert_relative_eq!(wf.word_frequency("a"), 0.4);
+        assert_relative_eq!(wf.word_frequency("b"), 0.3);
+        assert_relative_eq!(wf.word_frequency("c"), 0.3);
+    }
+
+    #[test]
+    fn test_merge_invalid() {
+        let wf = WordFreq::new([("a", 1.)]);
+        assert!(WordFreq::merge(&[], MergeStrategy::Mean).is_err());
+        assert!(WordFreq::merge(&[(&wf, 0.)], MergeStrategy::Mean).is_err());
+        assert!(WordFreq::merge(&[(&wf, Float::NAN)], MergeStrategy::Mean).is_err());
+        assert!(WordFreq::merge(&[(&wf, Float::INFINITY)], MergeStrategy::Mean).is_err());
+    }
+
+    #[test]
+    fn test_word_weights_from_text_error() {
+        let err = word_weights_from_text("las 10\nvegas\n".as_bytes()).unwrap_err();
+        assert!(err.to_string().starts_with("Line 2:"));
+    }
+
+    #[test]
+    fn test_cache() {
+        let wf = WordFreq::new([("las", 10.), ("vegas", 30.), ("0000", 10.)])
+            .cache(2)
+            .unwrap();
+        for word in ["las", "2023", "las", "nevada", "2023"] {
+            assert_relative_eq!(
+                wf.word_frequency(word),
+                WordFreq::new([("las", 10.), ("vegas", 30.), ("0000", 10.)]).word_frequency(word)
+            );
+        }
+        let stats = wf.cache_stats().unwrap();
+        assert_eq!((stats.hits, stats.misses, stats.len), (1, 4, 2));
+
+        // Changing the settings clears the cache.
+        let wf = wf.standardizer(Standardizer::new("en").unwrap());
+        assert_eq!(wf.cache_stats().unwrap().len, 0);
+        assert_relative_eq!(wf.word_frequency("LAS"), 0.2);
+
+        assert!(WordFreq::new([("las", 1.)]).cache(0).is_err());
+        assert_eq!(WordFreq::new([("las", 1.)]).cache_stats(), None);
+    }
+
     #[test]
     fn test_io() {
         let word_weights = [("las", 10.), ("vegas", 30.)];
@@ -338,4 +770,21 @@ mod tests {
 
         assert_eq!(wf.map, other.map);
     }
+
+    #[test]
+    fn test_serialized_format() {
+        // The same bytes as bincode 1.x.
+        let bytes = WordFreq::new([("las", 1.)]).serialize().unwrap();
+        assert_eq!(
+            bytes,
+            [3, 0, 0, 0, 0, 0, 0, 0, b'l', b'a', b's', 0, 0, 0x80, 0x3f]
+        );
+
+        for len in 1..bytes.len() {
+            assert!(WordFreq::deserialize(&bytes[..len]).is_err());
+        }
+        let mut invalid = bytes.clone();
+        invalid[8] = 0xff;
+        assert!(WordFreq::deserialize(&invalid).is_err());
+    }
 }
@@ -0,0 +1,109 @@
+// Copyright 2023 Shunsuke Kanda
+//! Floating-point functions that `core` does not provide.
+//! They are computed with [libm](https://docs.rs/libm) without the `std` feature.
+use crate::Float;
+
+#[cfg(feature = "std")]
+pub fn powf(x: Float, y: Float) -> Float {
+    x.powf(y)
+}
+
+#[cfg(not(feature = "std"))]
+pub fn powf(x: Float, y: Float) -> Float {
+    libm::powf(x, y)
+}
+
+#[cfg(feature = "std")]
+pub fn powi(x: Float, n: i32) -> Float {
+    x.powi(n)
+}
+
+#[cfg(not(feature = "std"))]
+pub fn powi(x: Float, n: i32) -> Float {
+    libm::powf(x, n as Float)
+}
+
+#[cfg(feature = "std")]
+// Not const to be consistent with libm.
+#[allow(clippy::missing_const_for_fn)]
+pub fn mul_add(x: Float, a: Float, b: Float) -> Float {
+    x.mul_add(a, b)
+}
+
+#[cfg(not(feature = "std"))]
+pub fn mul_add(x: Float, a: Float, b: Float) -> Float {
+    libm::fmaf(x, a, b)
+}
+
+#[cfg(feature = "std")]
+pub fn log10(x: Float) -> Float {
+    x.log10()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn log10(x: Float) -> Float {
+    libm::log10f(x)
+}
+
+#[cfg(feature = "std")]
+pub fn ln(x: Float) -> Float {
+    x.ln()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn ln(x: Float) -> Float {
+    libm::logf(x)
+}
+
+#[cfg(feature = "std")]
+pub fn log2(x: Float) -> Float {
+    x.log2()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn log2(x: Float) -> Float {
+    libm::log2f(x)
+}
+
+#[cfg(feature = "std")]
+pub fn sqrt(x: Float) -> Float {
+    x.sqrt()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn sqrt(x: Float) -> Float {
+    libm::sqrtf(x)
+}
+
+#[cfg(feature = "std")]
+// Not const to be consistent with libm.
+#[allow(clippy::missing_const_for_fn)]
+pub fn round(x: Float) -> Float {
+    x.round()
+}
+
+#[cfg(not(feature = "std"))]
+pub fn round(x: Float) -> Float {
+    libm::roundf(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_libm() {
+        // Checks that libm agrees with std, since the tests run with std.
+        for x in [1e-9, 0.001, 0.5, 1., 2.5, 1234.5] {
+            assert_relative_eq!(log10(x), libm::log10f(x));
+            assert_relative_eq!(ln(x), libm::logf(x));
+            assert_relative_eq!(log2(x), libm::log2f(x));
+            assert_relative_eq!(sqrt(x), libm::sqrtf(x));
+            assert_relative_eq!(powf(10., x), libm::powf(10., x), max_relative = 1e-6);
+            assert_eq!(round(x), libm::roundf(x));
+        }
+        assert_relative_eq!(powi(10., -3), libm::powf(10., -3.));
+    }
+}
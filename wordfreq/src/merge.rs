@@ -0,0 +1,101 @@
+// Copyright 2023 Shunsuke Kanda
+use crate::{math, Float};
+
+/// Strategies of merging word frequencies from multiple sources in [`WordFreq::merge()`](crate::WordFreq::merge).
+///
+/// A word missing in a source is regarded as having the frequency 0.0 in the source.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MergeStrategy {
+    /// Weighted mean of the frequencies.
+    Mean,
+    /// Weighted median of the frequencies.
+    ///
+    /// If the cumulative weight reaches exactly the half at a frequency,
+    /// the mean of it and the next one is taken.
+    Median,
+    /// Weighted mean of the frequencies after dropping the lowest one,
+    /// as Python's wordfreq builds its lists from multiple sources.
+    ///
+    /// It makes the result robust to a source that is missing a word or
+    /// that tokenizes it in a different way.
+    /// Nothing is dropped if there are two or fewer sources.
+    DropLowest,
+}
+
+/// Merges pairs of frequencies and their weights, where the weights are positive.
+pub fn merge_freqs(freq_weights: &mut [(Float, Float)], strategy: MergeStrategy) -> Float {
+    match strategy {
+        MergeStrategy::Mean => weighted_mean(freq_weights),
+        MergeStrategy::Median => weighted_median(freq_weights),
+        MergeStrategy::DropLowest => {
+            if freq_weights.len() <= 2 {
+                return weighted_mean(freq_weights);
+            }
+            let (lowest, _) = freq_weights
+                .iter()
+                .enumerate()
+                .min_by(|(_, (f1, _)), (_, (f2, _))| f1.total_cmp(f2))
+                .unwrap();
+            freq_weights.swap(0, lowest);
+            weighted_mean(&freq_weights[1..])
+        }
+    }
+}
+
+fn weighted_mean(freq_weights: &[(Float, Float)]) -> Float {
+    let (sum, total) = freq_weights.iter().fold((0., 0.), |(sum, total), &(f, w)| {
+        (math::mul_add(f, w, sum), total + w)
+    });
+    if total == 0. {
+        0.
+    } else {
+        sum / total
+    }
+}
+
+fn weighted_median(freq_weights: &mut [(Float, Float)]) -> Float {
+    freq_weights.sort_unstable_by(|(f1, _), (f2, _)| f1.total_cmp(f2));
+    let half = freq_weights.iter().map(|&(_, w)| w).sum::<Float>() / 2.;
+    let mut cum = 0.;
+    for (i, &(f, w)) in freq_weights.iter().enumerate() {
+        cum += w;
+        if cum > half {
+            return f;
+        }
+        if cum == half {
+            return freq_weights.get(i + 1).map_or(f, |&(g, _)| (f + g) / 2.);
+        }
+    }
+    0.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_mean() {
+        let mut fw = [(0.1, 1.), (0.2, 1.), (0.6, 2.)];
+        assert_relative_eq!(merge_freqs(&mut fw, MergeStrategy::Mean), 0.375);
+    }
+
+    #[test]
+    fn test_median() {
+        let mut fw = [(0.6, 1.), (0.1, 1.), (0.2, 1.)];
+        assert_relative_eq!(merge_freqs(&mut fw, MergeStrategy::Median), 0.2);
+        let mut fw = [(0.6, 1.), (0.1, 1.), (0.2, 1.), (0.4, 1.)];
+        assert_relative_eq!(merge_freqs(&mut fw, MergeStrategy::Median), 0.3);
+        let mut fw = [(0.6, 3.), (0.1, 1.), (0.2, 1.)];
+        assert_relative_eq!(merge_freqs(&mut fw, MergeStrategy::Median), 0.6);
+    }
+
+    #[test]
+    fn test_drop_lowest() {
+        let mut fw = [(0.2, 1.), (0., 1.), (0.4, 1.)];
+        assert_relative_eq!(merge_freqs(&mut fw, MergeStrategy::DropLowest), 0.3);
+        let mut fw = [(0.2, 1.), (0., 1.)];
+        assert_relative_eq!(merge_freqs(&mut fw, MergeStrategy::DropLowest), 0.1);
+    }
+}
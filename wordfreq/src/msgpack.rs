@@ -0,0 +1,234 @@
+// Copyright 2022 Robyn Speer
+// Copyright 2023 Shunsuke Kanda
+//
+// The code is based on https://github.com/rspeer/wordfreq/blob/v3.0.2/wordfreq/__init__.py,
+// following the MIT-license.
+use std::io::{BufRead, BufReader, Read, Write};
+
+use anyhow::{anyhow, Result};
+use flate2::bufread::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use rmpv::Value;
+
+use crate::Float;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Parses pairs of a word and its frequency from a word list in the cB format of
+/// Python's wordfreq, such as `large_en.msgpack.gz`.
+///
+/// The list is a MessagePack array whose first element is the header
+/// `{'format': 'cB', 'version': 1}`, followed by buckets of words.
+/// The words in the `i`-th bucket have the frequency of `-i` centibels, i.e., `10^(-i/100)`.
+/// The input can be either gzip-compressed or not.
+///
+/// An error is returned if the input is not a valid list in the format.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::fs::File;
+///
+/// let word_weights = wordfreq::word_weights_from_cb_msgpack(File::open("large_en.msgpack.gz")?)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn word_weights_from_cb_msgpack<R: Read>(rdr: R) -> Result<Vec<(String, Float)>> {
+    let mut rdr = BufReader::new(rdr);
+    let value = if rdr.fill_buf()?.starts_with(&GZIP_MAGIC) {
+        rmpv::decode::read_value(&mut GzDecoder::new(rdr))?
+    } else {
+        rmpv::decode::read_value(&mut rdr)?
+    };
+
+    let Value::Array(data) = value else {
+        return Err(anyhow!("The word list must be an array."));
+    };
+    let (header, buckets) = data
+        .split_first()
+        .ok_or_else(|| anyhow!("The word list must have a header."))?;
+    validate_header(header)?;
+
+    let mut word_weights = vec![];
+    for (index, bucket) in buckets.iter().enumerate() {
+        let bucket = bucket
+            .as_array()
+            .ok_or_else(|| anyhow!("Bucket {index}: a bucket must be an array of words."))?;
+        let freq = cb_to_freq(-(index as i32));
+        for word in bucket {
+            let word = word.as_str().ok_or_else(|| {
+                anyhow!("Bucket {index}: a word must be a string, but got {word}.")
+            })?;
+            word_weights.push((word.to_string(), freq));
+        }
+    }
+    Ok(word_weights)
+}
+
+/// Writes pairs of a word and its frequency as a gzip-compressed word list in the cB format,
+/// which is read by [`word_weights_from_cb_msgpack()`] and Python's wordfreq.
+///
+/// Each frequency is rounded to an integer number of centibels,
+/// and the words in each bucket are sorted.
+/// Words with non-positive frequencies are skipped.
+pub fn write_cb_msgpack<'a, I, W>(word_weights: I, wtr: W) -> Result<()>
+where
+    I: IntoIterator<Item = (&'a str, Float)>,
+    W: Write,
+{
+    let mut buckets: Vec<Vec<&str>> = vec![];
+    for (word, freq) in word_weights {
+        if freq <= 0. {
+            continue;
+        }
+        let index = freq_to_neg_cb(freq);
+        if buckets.len() <= index {
+            buckets.resize_with(index + 1, Vec::new);
+        }
+        buckets[index].push(word);
+    }
+
+    let mut data = Vec::with_capacity(buckets.len() + 1);
+    data.push(Value::Map(vec![
+        (Value::from("format"), Value::from("cB")),
+        (Value::from("version"), Value::from(1)),
+    ]));
+    for mut bucket in buckets {
+        bucket.sort_unstable();
+        data.push(Value::Array(bucket.into_iter().map(Value::from).collect()));
+    }
+
+    let mut encoder = GzEncoder::new(wtr, Compression::default());
+    rmpv::encode::write_value(&mut encoder, &Value::Array(data))?;
+    encoder.finish()?.flush()?;
+    Ok(())
+}
+
+fn validate_header(header: &Value) -> Result<()> {
+    let entries = header
+        .as_map()
+        .ok_or_else(|| anyhow!("Unexpected header: {header}"))?;
+    let get = |key: &str| {
+        entries
+            .iter()
+            .find_map(|(k, v)| (k.as_str() == Some(key)).then_some(v))
+    };
+    let format = get("format").and_then(Value::as_str);
+    let version = get("version").and_then(Value::as_u64);
+    if entries.len() != 2 || format != Some("cB") || version != Some(1) {
+        return Err(anyhow!("Unexpected header: {header}"));
+    }
+    Ok(())
+}
+
+/// Convert a word frequency from the logarithmic centibel scale that we use
+/// internally, to a proportion from 0 to 1.
+///
+/// On this scale, 0 cB represents the maximum possible frequency of
+/// 1.0. -100 cB represents a word that happens 1 in 10 times,
+/// -200 cB represents something that happens 1 in 100 times, and so on.
+///
+/// In general, x cB represents a frequency of 10 ** (x/100).
+fn cb_to_freq(cb: i32) -> Float {
+    debug_assert!(cb <= 0);
+    crate::FLOAT_10.powf(cb as Float / 100.)
+}
+
+// Inverse of cb_to_freq(), returning the index of the bucket, i.e., the negated centibels.
+// Frequencies above 1.0 are put into the first bucket.
+fn freq_to_neg_cb(freq: Float) -> usize {
+    debug_assert!(freq > 0.);
+    (-(freq.log10() * 100.).round()).max(0.) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    fn header() -> Value {
+        Value::Map(vec![
+            (Value::from("format"), Value::from("cB")),
+            (Value::from("version"), Value::from(1)),
+        ])
+    }
+
+    fn encode(value: &Value) -> Vec<u8> {
+        let mut bytes = vec![];
+        rmpv::encode::write_value(&mut bytes, value).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_cb_msgpack() {
+        let data = Value::Array(vec![
+            header(),
+            Value::Array(vec![]),
+            Value::Array(vec![Value::from("the"), Value::from("of")]),
+            Value::Array(vec![Value::from("las")]),
+        ]);
+        let bytes = encode(&data);
+        let mut encoder = GzEncoder::new(vec![], Compression::default());
+        encoder.write_all(&bytes).unwrap();
+        let gzipped = encoder.finish().unwrap();
+
+        for input in [bytes, gzipped] {
+            let word_weights = word_weights_from_cb_msgpack(input.as_slice()).unwrap();
+            assert_eq!(word_weights.len(), 3);
+            assert_eq!(word_weights[0].0, "the");
+            assert_relative_eq!(word_weights[0].1, 0.977_237_2);
+            assert_eq!(word_weights[2].0, "las");
+            assert_relative_eq!(word_weights[2].1, 0.954_992_6);
+        }
+    }
+
+    #[test]
+    fn test_write_cb_msgpack() {
+        let word_weights = [
+            ("las", 0.5),
+            ("vegas", 0.25),
+            ("palmas", 0.25),
+            ("zero", 0.),
+        ];
+        let mut bytes = vec![];
+        write_cb_msgpack(word_weights, &mut bytes).unwrap();
+        assert!(bytes.starts_with(&GZIP_MAGIC));
+
+        let decoded = word_weights_from_cb_msgpack(bytes.as_slice()).unwrap();
+        assert_eq!(decoded.len(), 3);
+        assert_eq!(decoded[0].0, "las");
+        assert_relative_eq!(decoded[0].1, cb_to_freq(-30));
+        // Sorted in the same bucket
+        assert_eq!(decoded[1].0, "palmas");
+        assert_eq!(decoded[2].0, "vegas");
+        assert_relative_eq!(decoded[2].1, cb_to_freq(-60));
+    }
+
+    #[test]
+    fn test_freq_to_neg_cb() {
+        assert_eq!(freq_to_neg_cb(1.), 0);
+        assert_eq!(freq_to_neg_cb(0.1), 100);
+        assert_eq!(freq_to_neg_cb(cb_to_freq(-523)), 523);
+        assert_eq!(freq_to_neg_cb(2.), 0);
+    }
+
+    #[test]
+    fn test_cb_msgpack_invalid() {
+        let wrong_header = Value::Map(vec![
+            (Value::from("format"), Value::from("cB")),
+            (Value::from("version"), Value::from(2)),
+        ]);
+        for data in [
+            Value::from("the"),
+            Value::Array(vec![]),
+            Value::Array(vec![wrong_header]),
+            Value::Array(vec![header(), Value::from("the")]),
+            Value::Array(vec![header(), Value::Array(vec![Value::from(1)])]),
+        ] {
+            assert!(word_weights_from_cb_msgpack(encode(&data).as_slice()).is_err());
+        }
+    }
+}
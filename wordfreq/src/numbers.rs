@@ -3,9 +3,35 @@
 //
 // The code is a port from https://github.com/rspeer/wordfreq/blob/v3.0.2/wordfreq/numbers.py
 // together with the comments, following the MIT-license.
+//! Handling of digit sequences.
+//!
+//! Word frequencies are aggregated over digit sequences of the same shape,
+//! i.e., all digits are replaced by zeroes (such as `"1991"` to `"0000"`), and
+//! the frequency of a particular sequence is estimated using Benford's law and a
+//! distribution of years. [`NumberHandler`] provides these operations.
+//!
+//! # Examples
+//!
+//! ```
+//! use wordfreq::numbers::NumberHandler;
+//!
+//! let handler = NumberHandler::new();
+//! assert_eq!(handler.smash_numbers("1991.08.07"), "0000.00.00");
+//! assert_eq!(handler.standardize_digits("١٩٩١"), "1991");
+//! assert!(handler.digit_freq("1991") > handler.digit_freq("4991"));
+//! ```
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use anyhow::{anyhow, Result};
 use hashbrown::HashMap;
 use regex::{Captures, Regex};
+use regex_syntax::hir::{Class, HirKind};
 
+use crate::math;
 use crate::Float;
 
 // Frequencies of leading digits, according to Benford's law, sort of.
@@ -29,688 +55,283 @@ const YEAR_LOG_PEAK: Float = -1.9185;
 const NOT_YEAR_PROB: Float = 0.1;
 const REFERENCE_YEAR: Float = 2019.;
 const PLATEAU_WIDTH: Float = 20.;
+const PAST_YEAR_SLOPE: Float = 0.0083;
+const FUTURE_YEAR_SLOPE: Float = 0.2;
 
 // To avoid annoying clippy errors.
 const FLOAT_10: Float = 10.;
-const FLOAT_0_2: Float = 0.2;
-const FLOAT_0_0083: Float = 0.0083;
-
-// Mapping from characters defined in \d (=\p{Nd}) to digits.
-const DIGIT_MAPPING: &[(char, u32)] = &[
-    ('0', 0),
-    ('1', 1),
-    ('2', 2),
-    ('3', 3),
-    ('4', 4),
-    ('5', 5),
-    ('6', 6),
-    ('7', 7),
-    ('8', 8),
-    ('9', 9),
-    ('٠', 0),
-    ('١', 1),
-    ('٢', 2),
-    ('٣', 3),
-    ('٤', 4),
-    ('٥', 5),
-    ('٦', 6),
-    ('٧', 7),
-    ('٨', 8),
-    ('٩', 9),
-    ('۰', 0),
-    ('۱', 1),
-    ('۲', 2),
-    ('۳', 3),
-    ('۴', 4),
-    ('۵', 5),
-    ('۶', 6),
-    ('۷', 7),
-    ('۸', 8),
-    ('۹', 9),
-    ('߀', 0),
-    ('߁', 1),
-    ('߂', 2),
-    ('߃', 3),
-    ('߄', 4),
-    ('߅', 5),
-    ('߆', 6),
-    ('߇', 7),
-    ('߈', 8),
-    ('߉', 9),
-    ('०', 0),
-    ('१', 1),
-    ('२', 2),
-    ('३', 3),
-    ('४', 4),
-    ('५', 5),
-    ('६', 6),
-    ('७', 7),
-    ('८', 8),
-    ('९', 9),
-    ('০', 0),
-    ('১', 1),
-    ('২', 2),
-    ('৩', 3),
-    ('৪', 4),
-    ('৫', 5),
-    ('৬', 6),
-    ('৭', 7),
-    ('৮', 8),
-    ('৯', 9),
-    ('੦', 0),
-    ('੧', 1),
-    ('੨', 2),
-    ('੩', 3),
-    ('੪', 4),
-    ('੫', 5),
-    ('੬', 6),
-    ('੭', 7),
-    ('੮', 8),
-    ('੯', 9),
-    ('૦', 0),
-    ('૧', 1),
-    ('૨', 2),
-    ('૩', 3),
-    ('૪', 4),
-    ('૫', 5),
-    ('૬', 6),
-    ('૭', 7),
-    ('૮', 8),
-    ('૯', 9),
-    ('୦', 0),
-    ('୧', 1),
-    ('୨', 2),
-    ('୩', 3),
-    ('୪', 4),
-    ('୫', 5),
-    ('୬', 6),
-    ('୭', 7),
-    ('୮', 8),
-    ('୯', 9),
-    ('௦', 0),
-    ('௧', 1),
-    ('௨', 2),
-    ('௩', 3),
-    ('௪', 4),
-    ('௫', 5),
-    ('௬', 6),
-    ('௭', 7),
-    ('௮', 8),
-    ('௯', 9),
-    ('౦', 0),
-    ('౧', 1),
-    ('౨', 2),
-    ('౩', 3),
-    ('౪', 4),
-    ('౫', 5),
-    ('౬', 6),
-    ('౭', 7),
-    ('౮', 8),
-    ('౯', 9),
-    ('೦', 0),
-    ('೧', 1),
-    ('೨', 2),
-    ('೩', 3),
-    ('೪', 4),
-    ('೫', 5),
-    ('೬', 6),
-    ('೭', 7),
-    ('೮', 8),
-    ('೯', 9),
-    ('൦', 0),
-    ('൧', 1),
-    ('൨', 2),
-    ('൩', 3),
-    ('൪', 4),
-    ('൫', 5),
-    ('൬', 6),
-    ('൭', 7),
-    ('൮', 8),
-    ('൯', 9),
-    ('෦', 0),
-    ('෧', 1),
-    ('෨', 2),
-    ('෩', 3),
-    ('෪', 4),
-    ('෫', 5),
-    ('෬', 6),
-    ('෭', 7),
-    ('෮', 8),
-    ('෯', 9),
-    ('๐', 0),
-    ('๑', 1),
-    ('๒', 2),
-    ('๓', 3),
-    ('๔', 4),
-    ('๕', 5),
-    ('๖', 6),
-    ('๗', 7),
-    ('๘', 8),
-    ('๙', 9),
-    ('໐', 0),
-    ('໑', 1),
-    ('໒', 2),
-    ('໓', 3),
-    ('໔', 4),
-    ('໕', 5),
-    ('໖', 6),
-    ('໗', 7),
-    ('໘', 8),
-    ('໙', 9),
-    ('༠', 0),
-    ('༡', 1),
-    ('༢', 2),
-    ('༣', 3),
-    ('༤', 4),
-    ('༥', 5),
-    ('༦', 6),
-    ('༧', 7),
-    ('༨', 8),
-    ('༩', 9),
-    ('၀', 0),
-    ('၁', 1),
-    ('၂', 2),
-    ('၃', 3),
-    ('၄', 4),
-    ('၅', 5),
-    ('၆', 6),
-    ('၇', 7),
-    ('၈', 8),
-    ('၉', 9),
-    ('႐', 0),
-    ('႑', 1),
-    ('႒', 2),
-    ('႓', 3),
-    ('႔', 4),
-    ('႕', 5),
-    ('႖', 6),
-    ('႗', 7),
-    ('႘', 8),
-    ('႙', 9),
-    ('០', 0),
-    ('១', 1),
-    ('២', 2),
-    ('៣', 3),
-    ('៤', 4),
-    ('៥', 5),
-    ('៦', 6),
-    ('៧', 7),
-    ('៨', 8),
-    ('៩', 9),
-    ('᠐', 0),
-    ('᠑', 1),
-    ('᠒', 2),
-    ('᠓', 3),
-    ('᠔', 4),
-    ('᠕', 5),
-    ('᠖', 6),
-    ('᠗', 7),
-    ('᠘', 8),
-    ('᠙', 9),
-    ('᥆', 0),
-    ('᥇', 1),
-    ('᥈', 2),
-    ('᥉', 3),
-    ('᥊', 4),
-    ('᥋', 5),
-    ('᥌', 6),
-    ('᥍', 7),
-    ('᥎', 8),
-    ('᥏', 9),
-    ('᧐', 0),
-    ('᧑', 1),
-    ('᧒', 2),
-    ('᧓', 3),
-    ('᧔', 4),
-    ('᧕', 5),
-    ('᧖', 6),
-    ('᧗', 7),
-    ('᧘', 8),
-    ('᧙', 9),
-    ('᪀', 0),
-    ('᪁', 1),
-    ('᪂', 2),
-    ('᪃', 3),
-    ('᪄', 4),
-    ('᪅', 5),
-    ('᪆', 6),
-    ('᪇', 7),
-    ('᪈', 8),
-    ('᪉', 9),
-    ('᪐', 0),
-    ('᪑', 1),
-    ('᪒', 2),
-    ('᪓', 3),
-    ('᪔', 4),
-    ('᪕', 5),
-    ('᪖', 6),
-    ('᪗', 7),
-    ('᪘', 8),
-    ('᪙', 9),
-    ('᭐', 0),
-    ('᭑', 1),
-    ('᭒', 2),
-    ('᭓', 3),
-    ('᭔', 4),
-    ('᭕', 5),
-    ('᭖', 6),
-    ('᭗', 7),
-    ('᭘', 8),
-    ('᭙', 9),
-    ('᮰', 0),
-    ('᮱', 1),
-    ('᮲', 2),
-    ('᮳', 3),
-    ('᮴', 4),
-    ('᮵', 5),
-    ('᮶', 6),
-    ('᮷', 7),
-    ('᮸', 8),
-    ('᮹', 9),
-    ('᱀', 0),
-    ('᱁', 1),
-    ('᱂', 2),
-    ('᱃', 3),
-    ('᱄', 4),
-    ('᱅', 5),
-    ('᱆', 6),
-    ('᱇', 7),
-    ('᱈', 8),
-    ('᱉', 9),
-    ('᱐', 0),
-    ('᱑', 1),
-    ('᱒', 2),
-    ('᱓', 3),
-    ('᱔', 4),
-    ('᱕', 5),
-    ('᱖', 6),
-    ('᱗', 7),
-    ('᱘', 8),
-    ('᱙', 9),
-    ('꘠', 0),
-    ('꘡', 1),
-    ('꘢', 2),
-    ('꘣', 3),
-    ('꘤', 4),
-    ('꘥', 5),
-    ('꘦', 6),
-    ('꘧', 7),
-    ('꘨', 8),
-    ('꘩', 9),
-    ('꣐', 0),
-    ('꣑', 1),
-    ('꣒', 2),
-    ('꣓', 3),
-    ('꣔', 4),
-    ('꣕', 5),
-    ('꣖', 6),
-    ('꣗', 7),
-    ('꣘', 8),
-    ('꣙', 9),
-    ('꤀', 0),
-    ('꤁', 1),
-    ('꤂', 2),
-    ('꤃', 3),
-    ('꤄', 4),
-    ('꤅', 5),
-    ('꤆', 6),
-    ('꤇', 7),
-    ('꤈', 8),
-    ('꤉', 9),
-    ('꧐', 0),
-    ('꧑', 1),
-    ('꧒', 2),
-    ('꧓', 3),
-    ('꧔', 4),
-    ('꧕', 5),
-    ('꧖', 6),
-    ('꧗', 7),
-    ('꧘', 8),
-    ('꧙', 9),
-    ('꧰', 0),
-    ('꧱', 1),
-    ('꧲', 2),
-    ('꧳', 3),
-    ('꧴', 4),
-    ('꧵', 5),
-    ('꧶', 6),
-    ('꧷', 7),
-    ('꧸', 8),
-    ('꧹', 9),
-    ('꩐', 0),
-    ('꩑', 1),
-    ('꩒', 2),
-    ('꩓', 3),
-    ('꩔', 4),
-    ('꩕', 5),
-    ('꩖', 6),
-    ('꩗', 7),
-    ('꩘', 8),
-    ('꩙', 9),
-    ('꯰', 0),
-    ('꯱', 1),
-    ('꯲', 2),
-    ('꯳', 3),
-    ('꯴', 4),
-    ('꯵', 5),
-    ('꯶', 6),
-    ('꯷', 7),
-    ('꯸', 8),
-    ('꯹', 9),
-    ('０', 0),
-    ('１', 1),
-    ('２', 2),
-    ('３', 3),
-    ('４', 4),
-    ('５', 5),
-    ('６', 6),
-    ('７', 7),
-    ('８', 8),
-    ('９', 9),
-    ('𐒠', 0),
-    ('𐒡', 1),
-    ('𐒢', 2),
-    ('𐒣', 3),
-    ('𐒤', 4),
-    ('𐒥', 5),
-    ('𐒦', 6),
-    ('𐒧', 7),
-    ('𐒨', 8),
-    ('𐒩', 9),
-    ('𐴰', 0),
-    ('𐴱', 1),
-    ('𐴲', 2),
-    ('𐴳', 3),
-    ('𐴴', 4),
-    ('𐴵', 5),
-    ('𐴶', 6),
-    ('𐴷', 7),
-    ('𐴸', 8),
-    ('𐴹', 9),
-    ('𑁦', 0),
-    ('𑁧', 1),
-    ('𑁨', 2),
-    ('𑁩', 3),
-    ('𑁪', 4),
-    ('𑁫', 5),
-    ('𑁬', 6),
-    ('𑁭', 7),
-    ('𑁮', 8),
-    ('𑁯', 9),
-    ('𑃰', 0),
-    ('𑃱', 1),
-    ('𑃲', 2),
-    ('𑃳', 3),
-    ('𑃴', 4),
-    ('𑃵', 5),
-    ('𑃶', 6),
-    ('𑃷', 7),
-    ('𑃸', 8),
-    ('𑃹', 9),
-    ('𑄶', 0),
-    ('𑄷', 1),
-    ('𑄸', 2),
-    ('𑄹', 3),
-    ('𑄺', 4),
-    ('𑄻', 5),
-    ('𑄼', 6),
-    ('𑄽', 7),
-    ('𑄾', 8),
-    ('𑄿', 9),
-    ('𑇐', 0),
-    ('𑇑', 1),
-    ('𑇒', 2),
-    ('𑇓', 3),
-    ('𑇔', 4),
-    ('𑇕', 5),
-    ('𑇖', 6),
-    ('𑇗', 7),
-    ('𑇘', 8),
-    ('𑇙', 9),
-    ('𑋰', 0),
-    ('𑋱', 1),
-    ('𑋲', 2),
-    ('𑋳', 3),
-    ('𑋴', 4),
-    ('𑋵', 5),
-    ('𑋶', 6),
-    ('𑋷', 7),
-    ('𑋸', 8),
-    ('𑋹', 9),
-    ('𑑐', 0),
-    ('𑑑', 1),
-    ('𑑒', 2),
-    ('𑑓', 3),
-    ('𑑔', 4),
-    ('𑑕', 5),
-    ('𑑖', 6),
-    ('𑑗', 7),
-    ('𑑘', 8),
-    ('𑑙', 9),
-    ('𑓐', 0),
-    ('𑓑', 1),
-    ('𑓒', 2),
-    ('𑓓', 3),
-    ('𑓔', 4),
-    ('𑓕', 5),
-    ('𑓖', 6),
-    ('𑓗', 7),
-    ('𑓘', 8),
-    ('𑓙', 9),
-    ('𑙐', 0),
-    ('𑙑', 1),
-    ('𑙒', 2),
-    ('𑙓', 3),
-    ('𑙔', 4),
-    ('𑙕', 5),
-    ('𑙖', 6),
-    ('𑙗', 7),
-    ('𑙘', 8),
-    ('𑙙', 9),
-    ('𑛀', 0),
-    ('𑛁', 1),
-    ('𑛂', 2),
-    ('𑛃', 3),
-    ('𑛄', 4),
-    ('𑛅', 5),
-    ('𑛆', 6),
-    ('𑛇', 7),
-    ('𑛈', 8),
-    ('𑛉', 9),
-    ('𑜰', 0),
-    ('𑜱', 1),
-    ('𑜲', 2),
-    ('𑜳', 3),
-    ('𑜴', 4),
-    ('𑜵', 5),
-    ('𑜶', 6),
-    ('𑜷', 7),
-    ('𑜸', 8),
-    ('𑜹', 9),
-    ('𑣠', 0),
-    ('𑣡', 1),
-    ('𑣢', 2),
-    ('𑣣', 3),
-    ('𑣤', 4),
-    ('𑣥', 5),
-    ('𑣦', 6),
-    ('𑣧', 7),
-    ('𑣨', 8),
-    ('𑣩', 9),
-    ('𑥐', 0),
-    ('𑥑', 1),
-    ('𑥒', 2),
-    ('𑥓', 3),
-    ('𑥔', 4),
-    ('𑥕', 5),
-    ('𑥖', 6),
-    ('𑥗', 7),
-    ('𑥘', 8),
-    ('𑥙', 9),
-    ('𑱐', 0),
-    ('𑱑', 1),
-    ('𑱒', 2),
-    ('𑱓', 3),
-    ('𑱔', 4),
-    ('𑱕', 5),
-    ('𑱖', 6),
-    ('𑱗', 7),
-    ('𑱘', 8),
-    ('𑱙', 9),
-    ('𑵐', 0),
-    ('𑵑', 1),
-    ('𑵒', 2),
-    ('𑵓', 3),
-    ('𑵔', 4),
-    ('𑵕', 5),
-    ('𑵖', 6),
-    ('𑵗', 7),
-    ('𑵘', 8),
-    ('𑵙', 9),
-    ('𑶠', 0),
-    ('𑶡', 1),
-    ('𑶢', 2),
-    ('𑶣', 3),
-    ('𑶤', 4),
-    ('𑶥', 5),
-    ('𑶦', 6),
-    ('𑶧', 7),
-    ('𑶨', 8),
-    ('𑶩', 9),
-    ('𖩠', 0),
-    ('𖩡', 1),
-    ('𖩢', 2),
-    ('𖩣', 3),
-    ('𖩤', 4),
-    ('𖩥', 5),
-    ('𖩦', 6),
-    ('𖩧', 7),
-    ('𖩨', 8),
-    ('𖩩', 9),
-    ('𖭐', 0),
-    ('𖭑', 1),
-    ('𖭒', 2),
-    ('𖭓', 3),
-    ('𖭔', 4),
-    ('𖭕', 5),
-    ('𖭖', 6),
-    ('𖭗', 7),
-    ('𖭘', 8),
-    ('𖭙', 9),
-    ('𝟎', 0),
-    ('𝟏', 1),
-    ('𝟐', 2),
-    ('𝟑', 3),
-    ('𝟒', 4),
-    ('𝟓', 5),
-    ('𝟔', 6),
-    ('𝟕', 7),
-    ('𝟖', 8),
-    ('𝟗', 9),
-    ('𝟘', 0),
-    ('𝟙', 1),
-    ('𝟚', 2),
-    ('𝟛', 3),
-    ('𝟜', 4),
-    ('𝟝', 5),
-    ('𝟞', 6),
-    ('𝟟', 7),
-    ('𝟠', 8),
-    ('𝟡', 9),
-    ('𝟢', 0),
-    ('𝟣', 1),
-    ('𝟤', 2),
-    ('𝟥', 3),
-    ('𝟦', 4),
-    ('𝟧', 5),
-    ('𝟨', 6),
-    ('𝟩', 7),
-    ('𝟪', 8),
-    ('𝟫', 9),
-    ('𝟬', 0),
-    ('𝟭', 1),
-    ('𝟮', 2),
-    ('𝟯', 3),
-    ('𝟰', 4),
-    ('𝟱', 5),
-    ('𝟲', 6),
-    ('𝟳', 7),
-    ('𝟴', 8),
-    ('𝟵', 9),
-    ('𝟶', 0),
-    ('𝟷', 1),
-    ('𝟸', 2),
-    ('𝟹', 3),
-    ('𝟺', 4),
-    ('𝟻', 5),
-    ('𝟼', 6),
-    ('𝟽', 7),
-    ('𝟾', 8),
-    ('𝟿', 9),
-    ('𞅀', 0),
-    ('𞅁', 1),
-    ('𞅂', 2),
-    ('𞅃', 3),
-    ('𞅄', 4),
-    ('𞅅', 5),
-    ('𞅆', 6),
-    ('𞅇', 7),
-    ('𞅈', 8),
-    ('𞅉', 9),
-    ('𞋰', 0),
-    ('𞋱', 1),
-    ('𞋲', 2),
-    ('𞋳', 3),
-    ('𞋴', 4),
-    ('𞋵', 5),
-    ('𞋶', 6),
-    ('𞋷', 7),
-    ('𞋸', 8),
-    ('𞋹', 9),
-    ('𞥐', 0),
-    ('𞥑', 1),
-    ('𞥒', 2),
-    ('𞥓', 3),
-    ('𞥔', 4),
-    ('𞥕', 5),
-    ('𞥖', 6),
-    ('𞥗', 7),
-    ('𞥘', 8),
-    ('𞥙', 9),
-    ('🯰', 0),
-    ('🯱', 1),
-    ('🯲', 2),
-    ('🯳', 3),
-    ('🯴', 4),
-    ('🯵', 5),
-    ('🯶', 6),
-    ('🯷', 7),
-    ('🯸', 8),
-    ('🯹', 9),
-];
 
+/// Parameters of the distributions used to estimate the frequencies of digit sequences.
+///
+/// A digit sequence is assigned a frequency according to Benford's law,
+/// except that a 4-digit sequence is also likely to be a year.
+/// The distribution of years is a piecewise exponential function whose peak is
+/// a plateau covering the "present" years, starting from the reference year.
+/// The log frequency decreases linearly with the given slopes before and after the plateau.
+///
+/// The default parameters are the same as those of Python's wordfreq,
+/// where the reference year is 2019, when the Google Books data was last collected.
+///
+/// # Examples
+///
+/// For historical newspapers, the "present" years can be set around 1890.
+///
+/// ```
+/// use wordfreq::{NumberModel, WordFreq};
+///
+/// let wf = WordFreq::new([("0000", 1.)]);
+/// assert!(wf.word_frequency("1890") < wf.word_frequency("2020"));
+///
+/// let model = NumberModel::new().reference_year(1885).plateau_width(10);
+/// let wf = wf.number_model(model);
+/// assert!(wf.word_frequency("1890") > wf.word_frequency("2020"));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumberModel {
+    reference_year: Float,
+    plateau_width: Float,
+    year_log_peak: Float,
+    past_year_slope: Float,
+    future_year_slope: Float,
+    not_year_prob: Float,
+    digit_freqs: [Float; 10],
+}
+
+impl Default for NumberModel {
+    fn default() -> Self {
+        Self {
+            reference_year: REFERENCE_YEAR,
+            plateau_width: PLATEAU_WIDTH,
+            year_log_peak: YEAR_LOG_PEAK,
+            past_year_slope: PAST_YEAR_SLOPE,
+            future_year_slope: FUTURE_YEAR_SLOPE,
+            not_year_prob: NOT_YEAR_PROB,
+            digit_freqs: DIGIT_FREQS,
+        }
+    }
+}
+
+impl NumberModel {
+    /// Creates a model with the default parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the first year of the plateau (default is 2019).
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn reference_year(mut self, year: i32) -> Self {
+        self.reference_year = year as Float;
+        self
+    }
+
+    /// Sets the number of years covered by the plateau (default is 20).
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn plateau_width(mut self, width: u32) -> Self {
+        self.plateau_width = width as Float;
+        self
+    }
+
+    /// Sets the log10 frequency of the years on the plateau (default is -1.9185).
+    ///
+    /// The default value makes the frequencies of all years add up to 90%,
+    /// with the other 10% going to the probability of not being a year.
+    /// If you change the shape of the distribution, [`NumberModel::fit_year_log_peak()`]
+    /// finds the value keeping the sum.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn year_log_peak(mut self, log_peak: Float) -> Self {
+        self.year_log_peak = log_peak;
+        self
+    }
+
+    /// Sets the decrease of the log10 frequency per year before the plateau (default is 0.0083).
+    ///
+    /// An error is returned if the input is negative.
+    pub fn past_year_slope(mut self, slope: Float) -> Result<Self> {
+        if slope < 0. {
+            return Err(anyhow!("past_year_slope must be non-negative"));
+        }
+        self.past_year_slope = slope;
+        Ok(self)
+    }
+
+    /// Sets the decrease of the log10 frequency per year after the plateau (default is 0.2).
+    ///
+    /// An error is returned if the input is negative.
+    pub fn future_year_slope(mut self, slope: Float) -> Result<Self> {
+        if slope < 0. {
+            return Err(anyhow!("future_year_slope must be non-negative"));
+        }
+        self.future_year_slope = slope;
+        Ok(self)
+    }
+
+    /// Sets the probability that a 4-digit sequence is not a year (default is 0.1).
+    ///
+    /// An error is returned if the input is not in `[0, 1]`.
+    pub fn not_year_prob(mut self, prob: Float) -> Result<Self> {
+        if !(0. ..=1.).contains(&prob) {
+            return Err(anyhow!("not_year_prob must be in [0, 1]"));
+        }
+        self.not_year_prob = prob;
+        Ok(self)
+    }
+
+    /// Sets the frequencies of leading digits from 0 to 9, used for Benford's law
+    /// (default is `[0.009, 0.300, 0.175, 0.124, 0.096, 0.078, 0.066, 0.057, 0.050, 0.045]`).
+    ///
+    /// An error is returned if the input contains a negative value.
+    pub fn digit_freqs(mut self, freqs: [Float; 10]) -> Result<Self> {
+        if freqs.iter().any(|&f| f < 0.) {
+            return Err(anyhow!("digit_freqs must be non-negative"));
+        }
+        self.digit_freqs = freqs;
+        Ok(self)
+    }
+
+    /// Sets the log10 frequency of the years on the plateau so that the frequencies of
+    /// all 4-digit years add up to `1 - not_year_prob`.
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use wordfreq::NumberModel;
+    ///
+    /// let model = NumberModel::new().plateau_width(5).fit_year_log_peak();
+    /// assert_relative_eq!(model.year_probs_sum(), 0.9, max_relative = 1e-4);
+    /// ```
+    pub fn fit_year_log_peak(mut self) -> Self {
+        // The sum is proportional to 10^peak, so fit it in the log space.
+        self.year_log_peak = 0.;
+        let log_sum = math::log10(self.year_probs_sum());
+        self.year_log_peak = math::log10(1. - self.not_year_prob) - log_sum;
+        self
+    }
+
+    /// Returns the sum of the frequencies of all 4-digit years from 0000 to 9999,
+    /// excluding the probability of not being a year.
+    pub fn year_probs_sum(&self) -> Float {
+        (0..10000)
+            .map(|year| math::powf(FLOAT_10, self.year_log_freq(year as Float)))
+            .sum()
+    }
+
+    fn year_log_freq(&self, year: Float) -> Float {
+        let reference_year = self.reference_year;
+        let plateau_width = self.plateau_width;
+        if year <= reference_year {
+            // Fitting a line to the curve seen at
+            // https://twitter.com/r_speer/status/1493715982887571456.
+            math::mul_add(
+                self.past_year_slope,
+                -reference_year + year,
+                self.year_log_peak,
+            )
+        } else if reference_year < year && year <= reference_year + plateau_width {
+            // It's no longer 2019, which is when the Google Books data was last collected.
+            // It's 2022 as I write this, and possibly even later as you're using it. Years
+            // keep happening.
+            //
+            // So, we'll just keep the expected frequency of the "present" year constant for
+            // 20 years.
+            self.year_log_peak
+        } else {
+            // Fall off quickly to catch up with the actual frequency of future years
+            // (it's low). This curve is made up to fit with the made-up "present" data above.
+            math::mul_add(
+                self.future_year_slope,
+                -year + (reference_year + plateau_width),
+                self.year_log_peak,
+            )
+        }
+    }
+}
+
+/// Builds the mapping from characters matched by `\d` (i.e., `\p{Nd}`) to ASCII digits,
+/// using the Unicode tables of the regex crate so that they never disagree.
+///
+/// Unicode guarantees that decimal digits are encoded in contiguous runs of ten
+/// characters from zero to nine, so the value of a digit is its offset in the run.
+fn decimal_digit_map() -> HashMap<char, char> {
+    let hir = regex_syntax::Parser::new().parse(r"\d").unwrap();
+    let HirKind::Class(Class::Unicode(class)) = hir.kind() else {
+        unreachable!("\\d must be a Unicode class");
+    };
+    digit_map_from_ranges(class.iter().map(|range| (range.start(), range.end())))
+}
+
+/// Maps the characters in the ranges of decimal digits, each of which starts with zero.
+///
+/// A range may consist of multiple runs of ten. A range ending in a partial run,
+/// such as a script whose digits are only partly assigned, is also mapped
+/// from its start, since every run starts with zero.
+fn digit_map_from_ranges<I>(ranges: I) -> HashMap<char, char>
+where
+    I: IntoIterator<Item = (char, char)>,
+{
+    let mut digit_map = HashMap::new();
+    for (start, end) in ranges {
+        let (start, end) = (start as u32, end as u32);
+        for code in start..=end {
+            let Some(c) = char::from_u32(code) else {
+                continue;
+            };
+            let d = char::from_digit((code - start) % 10, 10).unwrap();
+            digit_map.insert(c, d);
+        }
+    }
+    digit_map
+}
+
+/// Handler of digit sequences in words.
 #[derive(Clone)]
 pub struct NumberHandler {
     digit_re: Regex,
     multi_digit_re: Regex,
     pure_digit_re: Regex,
     digit_map: HashMap<char, char>,
+    model: NumberModel,
+    patterns: Vec<Arc<dyn NumberPattern>>,
+}
+
+impl Default for NumberHandler {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl NumberHandler {
+    /// Creates a handler with the default [`NumberModel`].
     pub fn new() -> Self {
-        let digit_map = DIGIT_MAPPING
-            .iter()
-            .map(|&(c, i)| (c, char::from_digit(i, 10).unwrap()))
-            .collect();
+        let digit_map = decimal_digit_map();
         Self {
             digit_re: Regex::new(r"\d").unwrap(),
             multi_digit_re: Regex::new(r"\d[\d.,]+").unwrap(),
             pure_digit_re: Regex::new(r"\d+").unwrap(),
             digit_map,
+            model: NumberModel::default(),
+            patterns: vec![],
         }
     }
 
+    /// Sets the parameters of the distributions.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn model(mut self, model: NumberModel) -> Self {
+        self.model = model;
+        self
+    }
+
+    /// Adds a numeric pattern with its own distribution.
+    ///
+    /// Patterns are tried in the order of addition, and the first one matching
+    /// the text is used in [`NumberHandler::digit_freq()`].
+    /// No pattern is set by default, following Python's wordfreq.
+    pub fn pattern<P>(mut self, pattern: P) -> Self
+    where
+        P: NumberPattern + 'static,
+    {
+        self.patterns.push(Arc::new(pattern));
+        self
+    }
+
     /// Replace sequences of multiple digits with zeroes, so we don't need to
     /// distinguish the frequencies of thousands of numbers.
     pub fn smash_numbers(&self, text: &str) -> String {
@@ -727,34 +348,57 @@ impl NumberHandler {
     }
 
     /// Get the relative frequency of a string of digits, using our estimates.
+    ///
+    /// If a pattern added with [`NumberHandler::pattern()`] matches the text,
+    /// its estimate is returned. Otherwise, the frequency is the product of those of
+    /// all the digit sequences in the text, so it is 1.0 if the text has no sequence
+    /// of multiple digits.
     pub fn digit_freq(&self, text: &str) -> Float {
+        if !self.patterns.is_empty() {
+            let standard = self.standardize_digits(text);
+            for pattern in &self.patterns {
+                if let Some(freq) = pattern.digit_freq(self, &standard) {
+                    return freq;
+                }
+            }
+        }
         let mut freq = 1.;
         for m in self.multi_digit_re.find_iter(text) {
             for sm in self.pure_digit_re.find_iter(m.as_str()) {
                 let sm = self.standardize_digits(sm.as_str());
+                // Skips a sequence with a digit of unknown value, which should not happen.
+                if !sm.bytes().all(|b| b.is_ascii_digit()) {
+                    continue;
+                }
                 if sm.len() == 4 {
-                    freq *= self.year_freq(sm.as_str());
+                    freq *= self.standard_year_freq(sm.as_str());
                 } else {
-                    freq *= self.benford_freq(sm.as_str());
+                    freq *= self.standard_benford_freq(sm.as_str());
                 }
             }
         }
         freq
     }
 
-    fn standardize_digits(&self, text: &str) -> String {
-        let mut result = String::new();
-        for c in text.chars() {
-            result.push(self.digit_map[&c]);
-        }
-        result
+    /// Replaces decimal digits in any script with the ASCII digits `0`-`9`.
+    ///
+    /// Characters other than decimal digits are left unchanged.
+    pub fn standardize_digits(&self, text: &str) -> String {
+        text.chars()
+            .map(|c| self.digit_map.get(&c).copied().unwrap_or(c))
+            .collect()
     }
 
     /// Estimate the frequency of a digit sequence according to Benford's law.
-    fn benford_freq(&self, text: &str) -> Float {
-        debug_assert_ne!(text.len(), 0);
-        let first_digit = text.chars().next().unwrap().to_digit(10).unwrap() as usize;
-        DIGIT_FREQS[first_digit] / FLOAT_10.powi(text.len() as i32 - 1)
+    ///
+    /// Digits in any script are accepted.
+    /// [`None`] is returned if the text is not a non-empty digit sequence.
+    pub fn benford_freq(&self, text: &str) -> Option<Float> {
+        let text = self.standardize_digits(text);
+        if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        Some(self.standard_benford_freq(&text))
     }
 
     /// Estimate the relative frequency of a particular 4-digit sequence representing
@@ -764,37 +408,302 @@ impl NumberHandler {
     /// randomly-selected token from a large corpus will be "1985" and refer to the
     /// year, _given_ that it is 4 digits. Tokens that are not 4 digits are not involved
     /// in the probability distribution.
-    fn year_freq(&self, text: &str) -> Float {
+    ///
+    /// Digits in any script are accepted.
+    /// [`None`] is returned if the text is not a 4-digit sequence.
+    pub fn year_freq(&self, text: &str) -> Option<Float> {
+        let text = self.standardize_digits(text);
+        if text.len() != 4 || !text.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        Some(self.standard_year_freq(&text))
+    }
+
+    // Same as benford_freq() for a sequence of ASCII digits.
+    fn standard_benford_freq(&self, text: &str) -> Float {
+        debug_assert_ne!(text.len(), 0);
+        let first_digit = text.chars().next().unwrap().to_digit(10).unwrap() as usize;
+        self.model.digit_freqs[first_digit] / math::powi(FLOAT_10, text.len() as i32 - 1)
+    }
+
+    // Same as year_freq() for a sequence of 4 ASCII digits.
+    fn standard_year_freq(&self, text: &str) -> Float {
         debug_assert_eq!(text.len(), 4);
         let year = text.parse::<Float>().unwrap();
 
-        let year_log_freq = if year <= REFERENCE_YEAR {
-            // Fitting a line to the curve seen at
-            // https://twitter.com/r_speer/status/1493715982887571456.
-            FLOAT_0_0083.mul_add(-REFERENCE_YEAR + year, YEAR_LOG_PEAK)
-        } else if REFERENCE_YEAR < year && year <= REFERENCE_YEAR + PLATEAU_WIDTH {
-            // It's no longer 2019, which is when the Google Books data was last collected.
-            // It's 2022 as I write this, and possibly even later as you're using it. Years
-            // keep happening.
-            //
-            // So, we'll just keep the expected frequency of the "present" year constant for
-            // 20 years.
-            YEAR_LOG_PEAK
-        } else {
-            // Fall off quickly to catch up with the actual frequency of future years
-            // (it's low). This curve is made up to fit with the made-up "present" data above.
-            FLOAT_0_2.mul_add(-year + (REFERENCE_YEAR + PLATEAU_WIDTH), YEAR_LOG_PEAK)
-        };
+        let year_log_freq = self.model.year_log_freq(year);
 
-        let year_prob = FLOAT_10.powf(year_log_freq);
+        let year_prob = math::powf(FLOAT_10, year_log_freq);
 
         // If this token _doesn't_ represent a year, then use the Benford frequency
         // distribution.
-        let not_year_prob = NOT_YEAR_PROB * self.benford_freq(text);
+        let not_year_prob = self.model.not_year_prob * self.standard_benford_freq(text);
         year_prob + not_year_prob
     }
 }
 
+/// Numeric pattern with its own distribution of digits, such as ordinals and times.
+///
+/// Like [`NumberHandler::digit_freq()`], an implementation estimates the relative
+/// frequency of the digits in a token among the tokens of the same shape, i.e., given
+/// the token whose digits are replaced by zeroes (such as `"00:00"` for `"12:30"`).
+///
+/// # Examples
+///
+/// ```
+/// use wordfreq::numbers::{NumberHandler, NumberPattern};
+/// use wordfreq::Float;
+///
+/// // Model numbers (such as "model 700") are multiples of 100 in this domain.
+/// struct ModelNumbers;
+///
+/// impl NumberPattern for ModelNumbers {
+///     fn digit_freq(&self, _handler: &NumberHandler, text: &str) -> Option<Float> {
+///         let n: u32 = text.strip_prefix("model ")?.parse().ok()?;
+///         Some(if n % 100 == 0 { 0.1 } else { 0. })
+///     }
+/// }
+///
+/// let handler = NumberHandler::new().pattern(ModelNumbers);
+/// assert_eq!(handler.digit_freq("model 700"), 0.1);
+/// assert_eq!(handler.digit_freq("model 701"), 0.);
+/// ```
+pub trait NumberPattern: Send + Sync {
+    /// Returns the relative frequency of the digits in the text among the texts of
+    /// the same shape, or [`None`] if the text does not match the pattern.
+    ///
+    /// The digits in the text are already standardized into ASCII digits.
+    /// Methods of the handler, such as [`NumberHandler::benford_freq()`], can be used
+    /// as building blocks.
+    fn digit_freq(&self, handler: &NumberHandler, text: &str) -> Option<Float>;
+}
+
+/// Ordinal numbers written with English suffixes, such as `"42nd"`.
+///
+/// Small ordinals are much more common than large ones, so the frequency of
+/// the number `n` is proportional to `1/n` among the numbers of the same length.
+/// Numbers with leading zeroes fall back to Benford's law.
+#[derive(Clone, Debug)]
+pub struct Ordinals {
+    re: Regex,
+}
+
+impl Default for Ordinals {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Ordinals {
+    /// Creates the pattern.
+    pub fn new() -> Self {
+        Self {
+            re: Regex::new(r"^(?i)([0-9]+)(?:st|nd|rd|th)$").unwrap(),
+        }
+    }
+}
+
+impl NumberPattern for Ordinals {
+    fn digit_freq(&self, handler: &NumberHandler, text: &str) -> Option<Float> {
+        let digits = self.re.captures(text)?.get(1)?.as_str();
+        if digits.len() > 1 && digits.starts_with('0') {
+            return handler.benford_freq(digits);
+        }
+        let n = digits.parse::<Float>().ok()?;
+        // Sum of 1/k for all k of the same length, approximated by the integral.
+        let lower = math::powi(FLOAT_10, digits.len() as i32 - 1);
+        let norm = math::ln((lower * 10. - 0.5) / (lower - 0.5));
+        Some(1. / (n.max(1.) * norm))
+    }
+}
+
+/// Percentages, such as `"12.5%"`.
+///
+/// The integer part is uniformly distributed from 0 to 100, except that values
+/// above 100 or with leading zeroes share 5% following Benford's law.
+/// The fractional part is uniformly distributed.
+#[derive(Clone, Debug)]
+pub struct Percentages {
+    re: Regex,
+}
+
+impl Default for Percentages {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Percentages {
+    /// Creates the pattern.
+    pub fn new() -> Self {
+        Self {
+            re: Regex::new(r"^([0-9]+)(?:[.,]([0-9]+))?\s?%$").unwrap(),
+        }
+    }
+}
+
+impl NumberPattern for Percentages {
+    fn digit_freq(&self, handler: &NumberHandler, text: &str) -> Option<Float> {
+        let captures = self.re.captures(text)?;
+        let integer = captures.get(1)?.as_str();
+        let n = integer.parse::<u64>().ok()?;
+        let leading_zero = integer.len() > 1 && integer.starts_with('0');
+        let mut freq = match integer.len() {
+            // The number of values of the same length from 0 to 100
+            1 => 0.95 / 10.,
+            2 if !leading_zero => 0.95 / 90.,
+            3 if n == 100 => 0.95,
+            _ => 0.05 * handler.benford_freq(integer)?,
+        };
+        if let Some(fraction) = captures.get(2) {
+            freq *= uniform_freq(fraction.as_str());
+        }
+        Some(freq)
+    }
+}
+
+/// Times of the day, such as `"12:30"`.
+///
+/// Hours are uniformly distributed, and minutes are biased toward multiples of 15 and 5.
+/// A single-digit hour is left as it is, because it is not replaced by zero
+/// in the smashed token (such as `"9:00"` for `"9:30"`).
+/// Texts with hours above 23 or minutes above 59 do not match.
+#[derive(Clone, Debug)]
+pub struct Times {
+    re: Regex,
+}
+
+impl Default for Times {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Times {
+    /// Creates the pattern.
+    pub fn new() -> Self {
+        Self {
+            re: Regex::new(r"^([0-9]{1,2}):([0-9]{2})$").unwrap(),
+        }
+    }
+}
+
+impl NumberPattern for Times {
+    fn digit_freq(&self, _handler: &NumberHandler, text: &str) -> Option<Float> {
+        let captures = self.re.captures(text)?;
+        let hour = captures.get(1)?.as_str();
+        let minute = captures.get(2)?.as_str().parse::<u32>().ok()?;
+        if hour.parse::<u32>().ok()? >= 24 || minute >= 60 {
+            return None;
+        }
+        let hour_freq = if hour.len() == 2 { 1. / 24. } else { 1. };
+        let minute_freq = if minute % 15 == 0 {
+            0.5 / 4.
+        } else if minute % 5 == 0 {
+            0.3 / 8.
+        } else {
+            0.2 / 48.
+        };
+        Some(hour_freq * minute_freq)
+    }
+}
+
+/// Decimal numbers and integers with thousands separators, such as `"3.14"` and `"2,000"`.
+///
+/// A number with thousands separators follows Benford's law as a whole, instead of
+/// each group of digits. A decimal number has the integer part following Benford's law
+/// and the fractional part uniformly distributed. A single separator followed by three
+/// digits, such as `"1.500"`, is taken as a thousands separator.
+#[derive(Clone, Debug)]
+pub struct Decimals {
+    grouped_re: Regex,
+    decimal_re: Regex,
+}
+
+impl Default for Decimals {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Decimals {
+    /// Creates the pattern.
+    pub fn new() -> Self {
+        Self {
+            grouped_re: Regex::new(r"^[0-9]{1,3}(?:(?:,[0-9]{3})+|(?:\.[0-9]{3})+)$").unwrap(),
+            decimal_re: Regex::new(r"^([0-9]+)[.,]([0-9]+)$").unwrap(),
+        }
+    }
+}
+
+impl NumberPattern for Decimals {
+    fn digit_freq(&self, handler: &NumberHandler, text: &str) -> Option<Float> {
+        if self.grouped_re.is_match(text) {
+            return handler.benford_freq(&ungroup(text));
+        }
+        let captures = self.decimal_re.captures(text)?;
+        let integer = handler.benford_freq(captures.get(1)?.as_str())?;
+        Some(integer * uniform_freq(captures.get(2)?.as_str()))
+    }
+}
+
+/// Currency amounts with a symbol before or after them, such as `"$1,000.99"` and `"12,50 €"`.
+///
+/// The integer part follows Benford's law as [`Decimals`] does, and two-digit cents
+/// are biased toward round and psychological prices such as `.00`, `.50` and `.99`.
+#[derive(Clone, Debug)]
+pub struct CurrencyAmounts {
+    re: Regex,
+}
+
+impl Default for CurrencyAmounts {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CurrencyAmounts {
+    /// Creates the pattern.
+    pub fn new() -> Self {
+        let amount = r"([0-9]{1,3}(?:[.,][0-9]{3})+|[0-9]+)(?:[.,]([0-9]{2}))?";
+        let symbol = r"[$€£¥₩₹]";
+        Self {
+            re: Regex::new(&format!(r"^(?:{symbol}\s?{amount}|{amount}\s?{symbol})$")).unwrap(),
+        }
+    }
+}
+
+impl NumberPattern for CurrencyAmounts {
+    fn digit_freq(&self, handler: &NumberHandler, text: &str) -> Option<Float> {
+        let captures = self.re.captures(text)?;
+        // The amount is captured by either of the alternatives.
+        let (integer, cents) = match captures.get(1) {
+            Some(m) => (m, captures.get(2)),
+            None => (captures.get(3)?, captures.get(4)),
+        };
+        let mut freq = handler.benford_freq(&ungroup(integer.as_str()))?;
+        if let Some(cents) = cents {
+            freq *= match cents.as_str() {
+                "00" => 0.4,
+                "99" => 0.2,
+                "50" => 0.1,
+                "95" => 0.05,
+                _ => 0.25 / 96.,
+            };
+        }
+        Some(freq)
+    }
+}
+
+// Frequency of a digit sequence when all digits are equally likely.
+fn uniform_freq(digits: &str) -> Float {
+    math::powi(FLOAT_10, -(digits.len() as i32))
+}
+
+fn ungroup(text: &str) -> String {
+    text.chars().filter(char::is_ascii_digit).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -812,29 +721,174 @@ mod tests {
     #[test]
     fn test_digit_freq() {
         let handler: NumberHandler = NumberHandler::new();
-        assert_relative_eq!(handler.digit_freq("1991.08.07"), 5.7467896897867986e-09);
-        assert_relative_eq!(handler.digit_freq("1991年08月07日"), 5.7467896897867986e-09);
+        assert_relative_eq!(handler.digit_freq("1991.08.07"), 5.746_789_7e-9);
+        assert_relative_eq!(handler.digit_freq("1991年08月07日"), 5.746_789_7e-9);
         assert_relative_eq!(handler.digit_freq("平成三年八月七日"), 1.0);
-        assert_relative_eq!(
-            handler.digit_freq("１９９１.０８.０７"),
-            5.7467896897867986e-09
-        );
+        assert_relative_eq!(handler.digit_freq("１９９１.０８.０７"), 5.746_789_7e-9);
     }
 
     #[test]
     fn test_benford_freq() {
         let handler: NumberHandler = NumberHandler::new();
-        assert_relative_eq!(handler.benford_freq("7"), 0.057);
-        assert_relative_eq!(handler.benford_freq("07"), 0.0009);
-        assert_relative_eq!(handler.benford_freq("007"), 8.999999999999999e-05);
+        assert_relative_eq!(handler.benford_freq("7").unwrap(), 0.057);
+        assert_relative_eq!(handler.benford_freq("07").unwrap(), 0.0009);
+        assert_relative_eq!(handler.benford_freq("007").unwrap(), 9e-5);
     }
 
     #[test]
     fn test_year_freq() {
         let handler: NumberHandler = NumberHandler::new();
-        assert_relative_eq!(handler.year_freq("1992"), 0.007231119202497894);
-        assert_relative_eq!(handler.year_freq("2023"), 0.012081740881970011);
-        assert_relative_eq!(handler.year_freq("0000"), 9.000000000002107e-07);
-        assert_relative_eq!(handler.year_freq("9999"), 4.5e-06);
+        assert_relative_eq!(handler.year_freq("1992").unwrap(), 0.007_231_119);
+        assert_relative_eq!(handler.year_freq("2023").unwrap(), 0.012_081_74);
+        assert_relative_eq!(handler.year_freq("0000").unwrap(), 9e-7);
+        assert_relative_eq!(handler.year_freq("9999").unwrap(), 4.5e-06);
+        assert_relative_eq!(handler.year_freq("١٩٩٢").unwrap(), 0.007_231_119);
+        assert_eq!(handler.year_freq("199"), None);
+        assert_eq!(handler.year_freq("199x"), None);
+    }
+
+    #[test]
+    fn test_standardize_digits() {
+        let handler: NumberHandler = NumberHandler::new();
+        assert_eq!(handler.standardize_digits("１９９１"), "1991");
+        assert_eq!(handler.standardize_digits("٢٠٢٣年"), "2023年");
+        assert_eq!(handler.standardize_digits("abc"), "abc");
+        assert_eq!(handler.benford_freq(""), None);
+        assert_eq!(handler.benford_freq("7a"), None);
+        // Mathematical digits and Unicode 15 digits (Kawi)
+        assert_eq!(handler.standardize_digits("𝟏𝟗𝟗𝟏"), "1991");
+        assert_eq!(handler.standardize_digits("\u{11F50}\u{11F59}"), "09");
+    }
+
+    #[test]
+    fn test_digit_map_from_ranges() {
+        // Two whole runs and a partial run
+        let digit_map = digit_map_from_ranges([('0', '9'), ('\u{660}', '\u{669}'), ('a', 'e')]);
+        assert_eq!(digit_map.len(), 25);
+        assert_eq!(digit_map[&'\u{669}'], '9');
+        assert_eq!(digit_map[&'a'], '0');
+        assert_eq!(digit_map[&'e'], '4');
+    }
+
+    #[test]
+    fn test_ordinals() {
+        let handler = NumberHandler::new().pattern(Ordinals::new());
+        assert!(handler.digit_freq("21st") > handler.digit_freq("42nd"));
+        assert!(handler.digit_freq("10th") > handler.digit_freq("99th"));
+        assert_relative_eq!(handler.digit_freq("007th"), 9e-05);
+        // Sum over the numbers of the same length
+        let sum: Float = (10..100)
+            .map(|n| handler.digit_freq(&format!("{n}th")))
+            .sum();
+        assert_relative_eq!(sum, 1.0, max_relative = 1e-2);
+    }
+
+    #[test]
+    fn test_percentages() {
+        let handler = NumberHandler::new().pattern(Percentages::new());
+        assert_relative_eq!(handler.digit_freq("42%"), 0.95 / 90.);
+        assert_relative_eq!(handler.digit_freq("100%"), 0.95);
+        assert_relative_eq!(handler.digit_freq("4.2 %"), 0.95 / 10. / 10.);
+        assert!(handler.digit_freq("200%") < handler.digit_freq("100%"));
+    }
+
+    #[test]
+    fn test_times() {
+        let handler = NumberHandler::new().pattern(Times::new());
+        assert_relative_eq!(handler.digit_freq("12:30"), 0.5 / 4. / 24.);
+        assert_relative_eq!(handler.digit_freq("9:45"), 0.5 / 4.);
+        assert!(handler.digit_freq("12:31") < handler.digit_freq("12:35"));
+        assert!(handler.digit_freq("12:35") < handler.digit_freq("12:30"));
+        // Falls back to the default distribution
+        assert_relative_eq!(
+            handler.digit_freq("25:30"),
+            NumberHandler::new().digit_freq("25:30")
+        );
+    }
+
+    #[test]
+    fn test_decimals() {
+        let handler = NumberHandler::new().pattern(Decimals::new());
+        assert_relative_eq!(handler.digit_freq("2,000"), 0.175 / 1000.);
+        assert_relative_eq!(handler.digit_freq("2.000.000"), 0.175 / 1e6);
+        assert_relative_eq!(handler.digit_freq("3.14"), 0.124 / 100.);
+        assert_relative_eq!(
+            handler.digit_freq("٣٫١٤"),
+            NumberHandler::new().digit_freq("٣٫١٤")
+        );
+        // Dates are not decimals
+        assert_relative_eq!(
+            handler.digit_freq("1991.08.07"),
+            NumberHandler::new().digit_freq("1991.08.07")
+        );
+    }
+
+    #[test]
+    fn test_currency_amounts() {
+        let handler = NumberHandler::new().pattern(CurrencyAmounts::new());
+        assert_relative_eq!(handler.digit_freq("$1,000.99"), 0.300 / 1000. * 0.2);
+        assert_relative_eq!(handler.digit_freq("12,50 €"), 0.300 / 10. * 0.1);
+        assert_relative_eq!(handler.digit_freq("¥1200"), 0.300 / 1000.);
+        assert!(handler.digit_freq("$9.99") > handler.digit_freq("$9.98"));
+    }
+
+    #[test]
+    fn test_pattern_order() {
+        let handler = NumberHandler::new()
+            .pattern(CurrencyAmounts::new())
+            .pattern(Decimals::new());
+        assert_relative_eq!(handler.digit_freq("$3.14"), 0.124 * 0.25 / 96.);
+        assert_relative_eq!(handler.digit_freq("3.14"), 0.124 / 100.);
+    }
+
+    #[test]
+    fn test_all_decimal_digits() {
+        // Checks that every character matched by \d is standardized and never panics.
+        let handler: NumberHandler = NumberHandler::new();
+        let digit_re = Regex::new(r"^\d$").unwrap();
+        let mut buf = [0; 4];
+        for c in (0..=char::MAX as u32).filter_map(char::from_u32) {
+            let text = c.encode_utf8(&mut buf);
+            if !digit_re.is_match(text) {
+                continue;
+            }
+            let digit = handler.standardize_digits(text);
+            assert!(
+                digit.len() == 1 && digit.as_bytes()[0].is_ascii_digit(),
+                "{c:?}"
+            );
+            let text = text.repeat(4);
+            assert!(handler.digit_freq(&text) > 0.);
+            assert!(handler.year_freq(&text).is_some());
+        }
+    }
+
+    #[test]
+    fn test_number_model() {
+        let model = NumberModel::new();
+        assert_relative_eq!(model.year_probs_sum(), 0.9, max_relative = 1e-3);
+        let fitted = model.clone().fit_year_log_peak();
+        assert_relative_eq!(fitted.year_log_peak, YEAR_LOG_PEAK, max_relative = 1e-3);
+
+        let model = NumberModel::new().reference_year(1885).plateau_width(10);
+        let handler = NumberHandler::new().model(model);
+        let peak = FLOAT_10.powf(YEAR_LOG_PEAK);
+        assert_relative_eq!(
+            handler.year_freq("1890").unwrap(),
+            NOT_YEAR_PROB.mul_add(handler.benford_freq("1890").unwrap(), peak)
+        );
+        assert_relative_eq!(
+            handler.year_freq("1895").unwrap(),
+            NOT_YEAR_PROB.mul_add(handler.benford_freq("1895").unwrap(), peak)
+        );
+        assert!(handler.year_freq("1896").unwrap() < handler.year_freq("1895").unwrap());
+    }
+
+    #[test]
+    fn test_number_model_invalid() {
+        assert!(NumberModel::new().past_year_slope(-1.).is_err());
+        assert!(NumberModel::new().future_year_slope(-1.).is_err());
+        assert!(NumberModel::new().not_year_prob(1.1).is_err());
+        assert!(NumberModel::new().digit_freqs([-0.1; 10]).is_err());
     }
 }
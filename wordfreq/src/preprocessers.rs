@@ -7,11 +7,13 @@
 // together with the comments, following the MIT-license.
 //! Preprocessers in multiple languages.
 
+use alloc::string::{String, ToString};
+
 use anyhow::{anyhow, Result};
-use language_tags::LanguageTag;
 use regex::Regex;
 use unicode_normalization::UnicodeNormalization;
 
+use crate::casefold::default_case_fold_str;
 use crate::chinese::ChineseSimplifier;
 use crate::language;
 use crate::transliterate::Transliterater;
@@ -219,6 +221,24 @@ enum DiacriticsUnder {
 /// assert_eq!(standardizer.apply("бағырты"), "bağırtı");
 /// ```
 ///
+/// Other languages written in both Cyrillic and Latin scripts are transliterated
+/// to Latin letters in the same way: Bosnian and Montenegrin (with the Serbian mapping),
+/// Uzbek, Kazakh, Tajik, and Mongolian.
+///
+/// ```
+/// use wordfreq::Standardizer;
+/// let standardizer = Standardizer::new("uz").unwrap();
+/// assert_eq!(standardizer.apply("Ўзбекистон"), "oʻzbekiston");
+/// let standardizer = Standardizer::new("kk").unwrap();
+/// assert_eq!(standardizer.apply("Қазақстан"), "qazaqstan");
+/// let standardizer = Standardizer::new("tg").unwrap();
+/// assert_eq!(standardizer.apply("Тоҷикистон"), "tojikiston");
+/// let standardizer = Standardizer::new("mn").unwrap();
+/// assert_eq!(standardizer.apply("Монгол Улс"), "mongol uls");
+/// let standardizer = Standardizer::new("sr-ME").unwrap();
+/// assert_eq!(standardizer.apply("с\u{0301}утра"), "śutra");
+/// ```
+///
 /// In Chinese, there is a transliteration step from traditional characters to simplified ones.
 ///
 /// ```
@@ -235,6 +255,9 @@ enum DiacriticsUnder {
 /// - **Chinese transliteration step:**
 ///   The original implementation performs this step during tokenization, but ours supports it in this class,
 ///   because our library does not support tokenization.
+/// - **Transliteration of other multi-script languages:**
+///   The original implementation transliterates only Serbian and Azerbaijani,
+///   but ours also supports the languages listed above.
 /// - **Language tag parsing:**
 ///   Our implementation employs a simple approach to parse language tags, just looking up [`language::LIKELY_SUBTAGS`].
 #[derive(Clone)]
@@ -257,9 +280,7 @@ impl Standardizer {
         let language_tag = language::maximize_subtag(language_tag).ok_or_else(|| anyhow!(
             "{language_tag} is an unexpected language tag. You must input a language tag defined in left keys of wordfreq::language::LIKELY_SUBTAGS."
         ))?;
-        let parsed = LanguageTag::parse(language_tag).unwrap();
-        let script = parsed.script().unwrap();
-        let primary_language = parsed.primary_language();
+        let (primary_language, script) = language::split_subtag(language_tag);
 
         let normal_form = if ["Latn", "Grek", "Cyrl"].contains(&script) {
             NormalForm::Nfc
@@ -283,13 +304,8 @@ impl Standardizer {
             (false, DiacriticsUnder::None)
         };
 
-        let transliterater = if "sr" == primary_language {
-            Some(Transliterater::new(Transliteration::SrLatn))
-        } else if "az" == primary_language {
-            Some(Transliterater::new(Transliteration::AzLatn))
-        } else {
-            None
-        };
+        let transliterater =
+            Transliteration::from_language(primary_language).map(Transliterater::new);
 
         let chinese_simplifier = if "zh" == primary_language && "Hant" != script {
             Some(ChineseSimplifier::new())
@@ -316,8 +332,15 @@ impl Standardizer {
         };
 
         // Transliteration of multi-script languages
+        // NOTE: The result is normalized again because transliteration can leave
+        // decomposed letters, such as a Latin letter followed by a combining mark
+        // that was attached to the original Cyrillic letter.
         let text = if let Some(transliterater) = self.transliterater.as_ref() {
-            transliterater.transliterate(&text)
+            let text = transliterater.transliterate(&text);
+            match self.normal_form {
+                NormalForm::Nfc => text.nfc().collect::<String>(),
+                NormalForm::Nfkc => text.nfkc().collect::<String>(),
+            }
         } else {
             text
         };
@@ -338,7 +361,7 @@ impl Standardizer {
         let text = if self.dotless_i {
             self.casefold_with_i_dots(&text)
         } else {
-            caseless::default_case_fold_str(&text)
+            default_case_fold_str(&text)
         };
 
         // Fixing of diacritics
@@ -365,7 +388,7 @@ impl Standardizer {
     fn casefold_with_i_dots(&self, text: &str) -> String {
         let text = text.nfc().collect::<String>();
         let text = text.replace('İ', "i").replace('I', "ı");
-        caseless::default_case_fold_str(&text)
+        default_case_fold_str(&text)
     }
 
     /// Converts s and t with commas (ș and ț) to cedillas (ş and ţ), which is
@@ -408,8 +431,11 @@ mod tests {
     #[test]
     fn test_langtag_parse() {
         for &(_, subtag) in language::LIKELY_SUBTAGS {
-            let langtag = LanguageTag::parse(subtag).unwrap();
-            assert!(langtag.script().is_some());
+            let langtag = language_tags::LanguageTag::parse(subtag).unwrap();
+            assert_eq!(
+                language::split_subtag(subtag),
+                (langtag.primary_language(), langtag.script().unwrap())
+            );
         }
     }
 
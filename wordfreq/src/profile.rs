@@ -0,0 +1,235 @@
+// Copyright 2023 Shunsuke Kanda
+use anyhow::{anyhow, Result};
+use hashbrown::HashMap;
+
+use crate::{Float, Tokenizer, WordFreq};
+
+// Zipf thresholds that roughly separate the vocabulary of CEFR levels,
+// from very rare words to the most basic ones.
+const DEFAULT_THRESHOLDS: [Float; 5] = [2.5, 3.0, 3.5, 4.0, 5.0];
+
+/// Profile of the vocabulary in a text, created by [`TextProfiler`].
+///
+/// Words unknown to the model are counted as tokens with the Zipf frequency of the minimum
+/// (0.0 by default), so rare words raise the difficulty even if they are out of vocabulary.
+/// All the statistics are 0.0 for a text without tokens.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextProfile {
+    /// Number of tokens.
+    pub num_tokens: usize,
+    /// Number of tokens unknown to the model.
+    pub num_oov_tokens: usize,
+    /// Mean of the Zipf frequencies of the tokens.
+    pub mean_zipf: Float,
+    /// Median of the Zipf frequencies of the tokens.
+    pub median_zipf: Float,
+    /// Pairs of a threshold and the share of the tokens whose Zipf frequencies are below it,
+    /// in ascending order of the thresholds.
+    pub shares_below: Vec<(Float, Float)>,
+    /// Distinct standardized words with the lowest Zipf frequencies, in ascending order.
+    pub rarest_words: Vec<(String, Float)>,
+}
+
+impl TextProfile {
+    /// Returns the share of the tokens unknown to the model.
+    pub fn oov_rate(&self) -> Float {
+        if self.num_tokens == 0 {
+            return 0.;
+        }
+        self.num_oov_tokens as Float / self.num_tokens as Float
+    }
+}
+
+/// Analyzer of the vocabulary difficulty of texts, based on the Zipf frequencies of [`WordFreq`].
+///
+/// Texts are split into tokens by [`Tokenizer`] using the model as the dictionary,
+/// and the tokens are standardized by the standardizer of the model, if any.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use wordfreq::{Standardizer, TextProfiler, WordFreq};
+///
+/// let wf = WordFreq::new([("the", 60.), ("cat", 30.), ("sat", 10.)])
+///     .standardizer(Standardizer::new("en")?);
+/// let profiler = TextProfiler::new(&wf, "en")?.thresholds(&[8.5])?.num_rarest(2);
+///
+/// let profile = profiler.profile("The cat sat on the mat.");
+/// assert_eq!(profile.num_tokens, 6);
+/// assert_eq!(profile.num_oov_tokens, 2);
+/// assert_eq!(profile.shares_below, vec![(8.5, 4. / 6.)]);
+/// assert_eq!(profile.rarest_words, vec![("mat".to_string(), 0.), ("on".to_string(), 0.)]);
+/// # Ok(())
+/// # }
+/// ```
+pub struct TextProfiler<'a> {
+    wf: &'a WordFreq,
+    tokenizer: Tokenizer,
+    thresholds: Vec<Float>,
+    num_rarest: usize,
+}
+
+impl<'a> TextProfiler<'a> {
+    /// Creates a profiler for the language with the default options.
+    ///
+    /// # Arguments
+    ///
+    /// - `wf`: Model of the language.
+    /// - `language_tag`: Language tag for the tokenizer, as in [`Tokenizer::new()`].
+    pub fn new(wf: &'a WordFreq, language_tag: &str) -> Result<Self> {
+        let tokenizer = Tokenizer::new(language_tag)?.dictionary(wf);
+        Ok(Self {
+            wf,
+            tokenizer,
+            thresholds: DEFAULT_THRESHOLDS.to_vec(),
+            num_rarest: 10,
+        })
+    }
+
+    /// Sets the Zipf thresholds of the bands (default is `[2.5, 3.0, 3.5, 4.0, 5.0]`).
+    ///
+    /// An error is returned if a threshold is not finite.
+    pub fn thresholds(mut self, thresholds: &[Float]) -> Result<Self> {
+        if thresholds.iter().any(|t| !t.is_finite()) {
+            return Err(anyhow!("thresholds must be finite"));
+        }
+        self.thresholds = thresholds.to_vec();
+        self.thresholds.sort_unstable_by(Float::total_cmp);
+        Ok(self)
+    }
+
+    /// Sets the number of the rarest words to report (default is 10).
+    pub const fn num_rarest(mut self, num: usize) -> Self {
+        self.num_rarest = num;
+        self
+    }
+
+    /// Analyzes the text.
+    pub fn profile(&self, text: &str) -> TextProfile {
+        let mut zipfs = vec![];
+        let mut num_oov_tokens = 0;
+        let mut words = HashMap::new();
+        for token in self.tokenizer.tokenize(text) {
+            let freq = self.wf.word_frequency_in(&token);
+            if freq.is_none() {
+                num_oov_tokens += 1;
+            }
+            let zipf = WordFreq::freq_to_rounded_zipf(freq, self.wf.minimum);
+            zipfs.push(zipf);
+            let word = match &self.wf.standardizer {
+                Some(standardizer) => standardizer.apply(&token),
+                None => token,
+            };
+            words.entry(word).or_insert(zipf);
+        }
+
+        let num_tokens = zipfs.len();
+        let shares_below = self
+            .thresholds
+            .iter()
+            .map(|&threshold| {
+                let num_below = zipfs.iter().filter(|&&zipf| zipf < threshold).count();
+                (threshold, ratio(num_below, num_tokens))
+            })
+            .collect();
+
+        let mut rarest_words: Vec<_> = words.into_iter().collect();
+        rarest_words.sort_unstable_by(|(w1, z1), (w2, z2)| z1.total_cmp(z2).then(w1.cmp(w2)));
+        rarest_words.truncate(self.num_rarest);
+
+        TextProfile {
+            num_tokens,
+            num_oov_tokens,
+            mean_zipf: if num_tokens == 0 {
+                0.
+            } else {
+                zipfs.iter().sum::<Float>() / num_tokens as Float
+            },
+            median_zipf: median(&mut zipfs),
+            shares_below,
+            rarest_words,
+        }
+    }
+}
+
+fn ratio(num: usize, den: usize) -> Float {
+    if den == 0 {
+        0.
+    } else {
+        num as Float / den as Float
+    }
+}
+
+fn median(values: &mut [Float]) -> Float {
+    if values.is_empty() {
+        return 0.;
+    }
+    values.sort_unstable_by(Float::total_cmp);
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2.
+    } else {
+        values[mid]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use approx::assert_relative_eq;
+
+    use crate::Standardizer;
+
+    fn example() -> WordFreq {
+        WordFreq::new([("the", 50.), ("cat", 30.), ("sat", 15.), ("mat", 5.)])
+            .standardizer(Standardizer::new("en").unwrap())
+    }
+
+    #[test]
+    fn test_profile() {
+        let wf = example();
+        let profiler = TextProfiler::new(&wf, "en")
+            .unwrap()
+            .thresholds(&[9., 8.5])
+            .unwrap()
+            .num_rarest(3);
+        let profile = profiler.profile("The cat sat on THE mat.");
+
+        let zipfs = [8.7, 8.48, 8.18, 0., 8.7, 7.7];
+        assert_eq!(profile.num_tokens, 6);
+        assert_eq!(profile.num_oov_tokens, 1);
+        assert_relative_eq!(profile.oov_rate(), 1. / 6.);
+        assert_relative_eq!(profile.mean_zipf, zipfs.iter().sum::<Float>() / 6.);
+        assert_relative_eq!(profile.median_zipf, (8.18 + 8.48) / 2.);
+        assert_eq!(profile.shares_below, vec![(8.5, 4. / 6.), (9., 1.)]);
+        assert_eq!(
+            profile.rarest_words,
+            vec![
+                ("on".to_string(), 0.),
+                ("mat".to_string(), 7.7),
+                ("sat".to_string(), 8.18)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let wf = example();
+        let profile = TextProfiler::new(&wf, "en").unwrap().profile("...");
+        assert_eq!(profile.num_tokens, 0);
+        assert_eq!(profile.oov_rate(), 0.);
+        assert_eq!(profile.mean_zipf, 0.);
+        assert_eq!(profile.median_zipf, 0.);
+        assert!(profile.shares_below.iter().all(|&(_, share)| share == 0.));
+        assert!(profile.rarest_words.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_thresholds() {
+        let wf = example();
+        let profiler = TextProfiler::new(&wf, "en").unwrap();
+        assert!(profiler.thresholds(&[Float::NAN]).is_err());
+    }
+}
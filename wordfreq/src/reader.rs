@@ -0,0 +1,308 @@
+// Copyright 2023 Shunsuke Kanda
+use std::io::{BufRead, Lines};
+
+use anyhow::{anyhow, Result};
+
+use crate::Float;
+
+/// Kinds of weights in word lists read by [`WordWeightReader`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WeightKind {
+    /// Non-negative real numbers, such as frequencies in arbitrary scales (default).
+    Weight,
+    /// Non-negative integers, such as raw counts in a corpus.
+    Count,
+    /// Probabilities in `[0, 1]`.
+    Probability,
+}
+
+/// Streaming reader of pairs of a word and its weight from a word list,
+/// such as TSV and CSV files.
+///
+/// It is an iterator of `Result<(String, Float)>`, and each error tells the line number (starting from 1).
+/// By default, it reads the same format as [`word_weights_from_text()`](crate::word_weights_from_text),
+/// except that extra columns and blank lines are allowed.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use wordfreq::{WeightKind, WordWeightReader};
+///
+/// let text = "# word\tpos\tcount\nlas\tDET\t10\n\n\"las vegas\"\tPROPN\t30\n";
+/// let word_weights = WordWeightReader::new(text.as_bytes())
+///     .delimiter(Some('\t'))
+///     .weight_column(2)
+///     .comment(Some('#'))
+///     .quoting(true)
+///     .weight_kind(WeightKind::Count)
+///     .collect::<Result<Vec<_>, _>>()?;
+///
+/// assert_eq!(
+///     word_weights,
+///     vec![("las".to_string(), 10.), ("las vegas".to_string(), 30.)]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub struct WordWeightReader<R> {
+    lines: Lines<R>,
+    line_no: usize,
+    delimiter: Option<char>,
+    word_column: usize,
+    weight_column: usize,
+    comment: Option<char>,
+    skip_blank_lines: bool,
+    weight_kind: WeightKind,
+    quoting: bool,
+}
+
+impl<R: BufRead> WordWeightReader<R> {
+    /// Creates a reader with the default options.
+    pub fn new(rdr: R) -> Self {
+        Self {
+            lines: rdr.lines(),
+            line_no: 0,
+            delimiter: None,
+            word_column: 0,
+            weight_column: 1,
+            comment: None,
+            skip_blank_lines: true,
+            weight_kind: WeightKind::Weight,
+            quoting: false,
+        }
+    }
+
+    /// Sets the delimiter of columns (default is [`None`]).
+    ///
+    /// If [`None`], columns are separated by runs of ASCII whitespace.
+    /// Otherwise, every occurrence of the character separates columns, so empty columns can appear.
+    pub const fn delimiter(mut self, delimiter: Option<char>) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets the zero-based index of the column of words (default is 0).
+    pub const fn word_column(mut self, column: usize) -> Self {
+        self.word_column = column;
+        self
+    }
+
+    /// Sets the zero-based index of the column of weights (default is 1).
+    pub const fn weight_column(mut self, column: usize) -> Self {
+        self.weight_column = column;
+        self
+    }
+
+    /// Sets the character starting comment lines, such as `#` (default is [`None`]).
+    ///
+    /// Lines starting with the character are skipped.
+    /// Note that it also skips words starting with the character, such as hashtags.
+    pub const fn comment(mut self, comment: Option<char>) -> Self {
+        self.comment = comment;
+        self
+    }
+
+    /// Sets whether to skip blank lines (default is true).
+    ///
+    /// If false, an error is returned for a blank line.
+    pub const fn skip_blank_lines(mut self, yes: bool) -> Self {
+        self.skip_blank_lines = yes;
+        self
+    }
+
+    /// Sets the kind of weights to validate them (default is [`WeightKind::Weight`]).
+    pub const fn weight_kind(mut self, kind: WeightKind) -> Self {
+        self.weight_kind = kind;
+        self
+    }
+
+    /// Sets whether to handle columns quoted with `"` as in CSV (default is false).
+    ///
+    /// A quoted column can contain delimiters, and `""` in it stands for `"`.
+    /// Quoted columns spanning multiple lines are not supported.
+    pub const fn quoting(mut self, yes: bool) -> Self {
+        self.quoting = yes;
+        self
+    }
+
+    fn parse_line(&self, line: &str) -> Result<(String, Float)> {
+        let columns = self.split_columns(line)?;
+        let get = |column: usize| {
+            columns.get(column).ok_or_else(|| {
+                anyhow!("a line should have more than {column} columns, but got {line}.")
+            })
+        };
+        let word = get(self.word_column)?;
+        let weight = get(self.weight_column)?;
+        let weight = match self.weight_kind {
+            WeightKind::Weight => weight.parse::<Float>()?,
+            WeightKind::Count => weight.parse::<u64>()? as Float,
+            WeightKind::Probability => weight.parse::<Float>()?,
+        };
+        if weight.is_nan()
+            || weight < 0.
+            || (self.weight_kind == WeightKind::Probability && weight > 1.)
+        {
+            return Err(anyhow!(
+                "{weight} is an invalid weight as {:?}.",
+                self.weight_kind
+            ));
+        }
+        Ok((word.to_string(), weight))
+    }
+
+    fn split_columns(&self, line: &str) -> Result<Vec<String>> {
+        let is_delimiter = |c: char| {
+            self.delimiter
+                .map_or_else(|| c.is_ascii_whitespace(), |d| c == d)
+        };
+        let mut columns = vec![];
+        let mut chars = line.chars().peekable();
+        loop {
+            if self.delimiter.is_none() {
+                while chars.next_if(|&c| is_delimiter(c)).is_some() {}
+                if chars.peek().is_none() {
+                    break;
+                }
+            }
+            let mut column = String::new();
+            if self.quoting && chars.next_if_eq(&'"').is_some() {
+                loop {
+                    match chars.next() {
+                        Some('"') if chars.next_if_eq(&'"').is_some() => column.push('"'),
+                        Some('"') => break,
+                        Some(c) => column.push(c),
+                        None => return Err(anyhow!("a quoted column is not closed in {line}.")),
+                    }
+                }
+                if chars.peek().is_some_and(|&c| !is_delimiter(c)) {
+                    return Err(anyhow!(
+                        "a quoted column is followed by a non-delimiter in {line}."
+                    ));
+                }
+            } else {
+                while let Some(c) = chars.next_if(|&c| !is_delimiter(c)) {
+                    column.push(c);
+                }
+            }
+            columns.push(column);
+            // Consumes the delimiter, if any.
+            if chars.next().is_none() {
+                break;
+            }
+            if self.delimiter.is_some() && chars.peek().is_none() {
+                // A trailing delimiter makes an empty column.
+                columns.push(String::new());
+                break;
+            }
+        }
+        Ok(columns)
+    }
+}
+
+impl<R: BufRead> Iterator for WordWeightReader<R> {
+    type Item = Result<(String, Float)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+            self.line_no += 1;
+            if self.skip_blank_lines && line.trim().is_empty() {
+                continue;
+            }
+            if self.comment.is_some_and(|c| line.starts_with(c)) {
+                continue;
+            }
+            let line_no = self.line_no;
+            return Some(
+                self.parse_line(&line)
+                    .map_err(|e| anyhow!("Line {line_no}: {e}")),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read<R: BufRead>(reader: WordWeightReader<R>) -> Result<Vec<(String, Float)>> {
+        reader.collect()
+    }
+
+    #[test]
+    fn test_default() {
+        let text = "las 10\n\n  vegas\t30 extra\n";
+        assert_eq!(
+            read(WordWeightReader::new(text.as_bytes())).unwrap(),
+            vec![("las".to_string(), 10.), ("vegas".to_string(), 30.)]
+        );
+    }
+
+    #[test]
+    fn test_columns() {
+        let text = "1,las,0.25\n2,vegas,0.75\n";
+        let reader = WordWeightReader::new(text.as_bytes())
+            .delimiter(Some(','))
+            .word_column(1)
+            .weight_column(2)
+            .weight_kind(WeightKind::Probability);
+        assert_eq!(
+            read(reader).unwrap(),
+            vec![("las".to_string(), 0.25), ("vegas".to_string(), 0.75)]
+        );
+    }
+
+    #[test]
+    fn test_quoting() {
+        let text = "\"las, vegas\",10\n\"say \"\"hi\"\"\",20\n\"\",30\n";
+        let reader = WordWeightReader::new(text.as_bytes())
+            .delimiter(Some(','))
+            .quoting(true);
+        assert_eq!(
+            read(reader).unwrap(),
+            vec![
+                ("las, vegas".to_string(), 10.),
+                ("say \"hi\"".to_string(), 20.),
+                (String::new(), 30.)
+            ]
+        );
+
+        let text = "\"las 10\n";
+        let reader = WordWeightReader::new(text.as_bytes()).quoting(true);
+        assert!(read(reader).is_err());
+    }
+
+    #[test]
+    fn test_comment() {
+        let text = "#word count\n#rust 10\nlas 10\n";
+        let reader = WordWeightReader::new(text.as_bytes()).comment(Some('#'));
+        assert_eq!(read(reader).unwrap(), vec![("las".to_string(), 10.)]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let text = "las 10\n\nvegas\n";
+        let err = read(WordWeightReader::new(text.as_bytes())).unwrap_err();
+        assert!(err.to_string().starts_with("Line 3:"));
+
+        let reader = WordWeightReader::new(text.as_bytes()).skip_blank_lines(false);
+        let err = read(reader).unwrap_err();
+        assert!(err.to_string().starts_with("Line 2:"));
+
+        let text = "las 1.5\n";
+        let reader = WordWeightReader::new(text.as_bytes()).weight_kind(WeightKind::Count);
+        assert!(read(reader).is_err());
+
+        let text = "las 1.5\n";
+        let reader = WordWeightReader::new(text.as_bytes()).weight_kind(WeightKind::Probability);
+        assert!(read(reader).is_err());
+
+        let text = "las -1\n";
+        assert!(read(WordWeightReader::new(text.as_bytes())).is_err());
+    }
+}
@@ -0,0 +1,218 @@
+// Copyright 2023 Shunsuke Kanda
+//
+// The algorithm follows the dictionary-based segmentation of jieba
+// (https://github.com/fxsjy/jieba), used by Python's wordfreq for Chinese.
+use hashbrown::HashMap;
+use unicode_normalization::char::is_combining_mark;
+
+use crate::chinese::ChineseSimplifier;
+use crate::Float;
+
+/// Max-probability segmenter using a word list as the dictionary.
+///
+/// Like jieba without its HMM, it builds a DAG of all dictionary words found
+/// in the text and picks the path maximizing the product of the word
+/// probabilities with dynamic programming. Characters not covered by any
+/// dictionary word are emitted one by one, together with their combining marks,
+/// with a probability below the smallest one in the dictionary.
+#[derive(Clone)]
+pub struct Segmenter {
+    log_probs: HashMap<String, Float>,
+    max_word_len: usize,
+    unknown_log_prob: Float,
+    word_list: bool,
+    chinese_simplifier: Option<ChineseSimplifier>,
+}
+
+impl Segmenter {
+    /// Creates a segmenter from pairs of words and their probabilities.
+    pub fn new<I, W>(word_probs: I) -> Self
+    where
+        I: IntoIterator<Item = (W, Float)>,
+        W: AsRef<str>,
+    {
+        let mut log_probs = HashMap::new();
+        let mut max_word_len = 1;
+        let mut min_prob = Float::MAX;
+        for (word, prob) in word_probs {
+            let word = word.as_ref();
+            if prob <= 0. || word.chars().any(char::is_whitespace) {
+                continue;
+            }
+            max_word_len = max_word_len.max(word.chars().count());
+            min_prob = min_prob.min(prob);
+            log_probs.insert(word.to_string(), prob.ln());
+        }
+        // Strictly worse than any dictionary word, so that known words are always preferred.
+        let unknown_log_prob = if log_probs.is_empty() {
+            0.
+        } else {
+            min_prob.ln() - 1.
+        };
+        Self {
+            log_probs,
+            max_word_len,
+            unknown_log_prob,
+            word_list: false,
+            chinese_simplifier: None,
+        }
+    }
+
+    /// Creates a segmenter from a word list, which leaves as few characters
+    /// outside the list as possible and then uses the fewest words.
+    pub fn word_list<I, W>(words: I) -> Self
+    where
+        I: IntoIterator<Item = W>,
+        W: AsRef<str>,
+    {
+        // Any probability below 1.0 works, since every word has the same one.
+        let prob = (-1. as Float).exp();
+        let mut segmenter = Self::new(words.into_iter().map(|word| (word, prob)));
+        segmenter.word_list = true;
+        segmenter
+    }
+
+    /// Looks up the dictionary with characters converted to Simplified Chinese,
+    /// since the Chinese word lists are written in them.
+    pub fn chinese(mut self) -> Self {
+        self.chinese_simplifier = Some(ChineseSimplifier::new());
+        self
+    }
+
+    /// Splits the text into the most probable sequence of words.
+    pub fn segment<'a>(&self, text: &'a str) -> Vec<&'a str> {
+        let mut offsets: Vec<_> = text.char_indices().map(|(i, _)| i).collect();
+        offsets.push(text.len());
+        let chars: Vec<_> = text
+            .chars()
+            .map(|c| {
+                self.chinese_simplifier
+                    .as_ref()
+                    .map_or(c, |simplifier| simplifier.simplify_char(c))
+            })
+            .collect();
+
+        // route[i] = (the best log probability of chars[i..], the end of the first word)
+        let n = chars.len();
+        // With a word list, an unknown character costs more than all the words in the text,
+        // so the number of unknown characters is minimized before that of words.
+        let unknown_log_prob = if self.word_list {
+            -(n as Float + 1.)
+        } else {
+            self.unknown_log_prob
+        };
+        let mut route = vec![(0., n); n + 1];
+        let mut key = String::new();
+        for i in (0..n).rev() {
+            // An unknown character is kept with the combining marks following it.
+            let mut end = i + 1;
+            while end < n && is_combining_mark(chars[end]) {
+                end += 1;
+            }
+            let mut best = (unknown_log_prob + route[end].0, end);
+            key.clear();
+            for j in i..n.min(i + self.max_word_len) {
+                key.push(chars[j]);
+                // Words must not separate combining marks from their base characters.
+                if j + 1 < n && is_combining_mark(chars[j + 1]) {
+                    continue;
+                }
+                if let Some(&log_prob) = self.log_probs.get(key.as_str()) {
+                    let score = log_prob + route[j + 1].0;
+                    // Prefers longer words on ties, as jieba does.
+                    if score >= best.0 {
+                        best = (score, j + 1);
+                    }
+                }
+            }
+            route[i] = best;
+        }
+
+        let mut words = vec![];
+        let mut i = 0;
+        while i < n {
+            let end = route[i].1;
+            words.push(&text[offsets[i]..offsets[end]]);
+            i = end;
+        }
+        words
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment() {
+        let word_probs = [
+            ("我", 0.2),
+            ("来到", 0.1),
+            ("北京", 0.1),
+            ("清华", 0.05),
+            ("清华大学", 0.05),
+            ("华大", 0.01),
+            ("大学", 0.1),
+        ];
+        let segmenter = Segmenter::new(word_probs);
+        assert_eq!(
+            segmenter.segment("我来到北京清华大学"),
+            vec!["我", "来到", "北京", "清华大学"]
+        );
+    }
+
+    #[test]
+    fn test_segment_unknown() {
+        let segmenter = Segmenter::new([("北京", 0.5), ("大学", 0.5)]);
+        assert_eq!(segmenter.segment("北京的大学"), vec!["北京", "的", "大学"]);
+    }
+
+    #[test]
+    fn test_segment_traditional() {
+        let segmenter = Segmenter::new([("爱情", 1.)]).chinese();
+        assert_eq!(segmenter.segment("愛情"), vec!["愛情"]);
+    }
+
+    #[test]
+    fn test_segment_empty() {
+        let segmenter = Segmenter::new(Vec::<(&str, Float)>::new());
+        assert!(segmenter.segment("").is_empty());
+        assert_eq!(segmenter.segment("北京"), vec!["北", "京"]);
+    }
+
+    #[test]
+    fn test_segment_thai() {
+        let segmenter = Segmenter::new([("ภาษา", 0.4), ("ไทย", 0.4), ("ภา", 0.1), ("ษาไทย", 0.1)]);
+        assert_eq!(segmenter.segment("ภาษาไทย"), vec!["ภาษา", "ไทย"]);
+    }
+
+    #[test]
+    fn test_segment_combining_marks() {
+        let segmenter = Segmenter::new([("ภาษา", 1.)]);
+        assert_eq!(
+            segmenter.segment("ภาษาง่าย"),
+            vec!["ภาษา", "ง\u{e48}", "า", "ย"]
+        );
+        // "ง" is in the list, but the tone mark is not split from it.
+        let segmenter = Segmenter::new([("ภาษา", 0.5), ("ง", 0.5)]);
+        assert_eq!(
+            segmenter.segment("ภาษาง่าย"),
+            vec!["ภาษา", "ง\u{e48}", "า", "ย"]
+        );
+    }
+
+    #[test]
+    fn test_segment_fewest_words() {
+        let segmenter = Segmenter::word_list(["กข", "กขค", "คงจ"]);
+        assert_eq!(segmenter.segment("กขคงจ"), vec!["กข", "คงจ"]);
+    }
+
+    #[test]
+    fn test_segment_fewest_unknown_chars() {
+        // "กขค" and the unknown "ง" are fewer tokens, but leave a character outside the list.
+        let segmenter = Segmenter::word_list(["กขค", "ก", "ข", "คง"]);
+        assert_eq!(segmenter.segment("กขคง"), vec!["ก", "ข", "คง"]);
+        let segmenter = Segmenter::word_list(["กขค", "ก", "ข"]);
+        assert_eq!(segmenter.segment("กขคง"), vec!["กขค", "ง"]);
+    }
+}
@@ -0,0 +1,357 @@
+// Copyright 2022 Robyn Speer
+// Copyright 2023 Shunsuke Kanda
+//
+// The code is based on https://github.com/rspeer/wordfreq/blob/v3.0.2/wordfreq/tokens.py,
+// following the MIT-license.
+//! Tokenizers in multiple languages.
+
+use anyhow::{anyhow, Result};
+use regex::Regex;
+
+use crate::language;
+use crate::segmenter::Segmenter;
+use crate::WordFreq;
+
+// Characters in scripts that are written without spaces. Their runs are kept
+// together as single tokens unless a dictionary-based segmenter splits them.
+// \u{30FC} is the Katakana-Hiragana prolonged sound mark (ー) in the Common script.
+const SPACELESS_CLASS: &str =
+    r"\p{Han}\p{Hiragana}\p{Katakana}\u{30FC}\p{Thai}\p{Lao}\p{Khmer}\p{Myanmar}\p{Tibetan}";
+
+/// This class splits text into tokens, in the same spirit as `tokenize` in
+/// [wordfreq/tokens.py](https://github.com/rspeer/wordfreq/blob/v3.0.2/wordfreq/tokens.py).
+///
+/// Tokens are sequences of word characters, allowing inner apostrophes as in
+/// "don't" and separators inside numbers as in "2,000". The surface forms are
+/// returned as is, so apply a [`Standardizer`](crate::Standardizer) to them
+/// (or let [`WordFreq`] do it) before looking up their frequencies.
+///
+/// ```
+/// use wordfreq::Tokenizer;
+/// let tokenizer = Tokenizer::new("en").unwrap();
+/// assert_eq!(
+///     tokenizer.tokenize("I don't have 2,000 dollars."),
+///     vec!["I", "don't", "have", "2,000", "dollars"]
+/// );
+/// ```
+///
+/// # Chinese
+///
+/// Chinese is written without spaces, so runs of Chinese characters are kept
+/// as single tokens by default.
+/// Given a dictionary with [`Tokenizer::dictionary()`], they are segmented into
+/// words with a max-probability segmenter in the manner of [jieba](https://github.com/fxsjy/jieba),
+/// which Python's wordfreq uses for Chinese.
+/// The word frequencies of the dictionary serve as the word probabilities.
+///
+/// ```
+/// use wordfreq::{Tokenizer, WordFreq};
+/// let wf = WordFreq::new([("我", 20.), ("来到", 10.), ("北京", 10.), ("清华大学", 5.), ("华大", 1.), ("大学", 10.)]);
+/// let tokenizer = Tokenizer::new("zh").unwrap();
+/// assert_eq!(tokenizer.tokenize("我来到北京清华大学"), vec!["我来到北京清华大学"]);
+/// let tokenizer = tokenizer.dictionary(&wf);
+/// assert_eq!(tokenizer.tokenize("我来到北京清华大学"), vec!["我", "来到", "北京", "清华大学"]);
+/// ```
+///
+/// Traditional characters are converted to simplified ones only to look up
+/// the dictionary, and the original characters are returned.
+///
+/// ```
+/// use wordfreq::{Tokenizer, WordFreq};
+/// let wf = WordFreq::new([("爱情", 1.), ("故事", 1.)]);
+/// let tokenizer = Tokenizer::new("zh").unwrap().dictionary(&wf);
+/// assert_eq!(tokenizer.tokenize("愛情故事"), vec!["愛情", "故事"]);
+/// ```
+///
+/// # Thai, Lao, Khmer, and Burmese
+///
+/// These languages are also written without spaces between words, and
+/// runs of their scripts are segmented in the same manner when a dictionary is given.
+/// Instead of a [`WordFreq`] instance, you can give a plain word list with [`Tokenizer::word_list()`],
+/// in which case the text is split so as to leave as few characters outside the list as possible,
+/// and then into the fewest words.
+///
+/// ```
+/// use wordfreq::{Tokenizer, WordFreq};
+/// let tokenizer = Tokenizer::new("th").unwrap();
+/// assert_eq!(tokenizer.tokenize("ภาษาไทยง่ายนิดเดียว"), vec!["ภาษาไทยง่ายนิดเดียว"]);
+/// let tokenizer = tokenizer.word_list(["ภาษา", "ไทย", "ง่าย", "นิด", "เดียว", "นิดเดียว"]);
+/// assert_eq!(tokenizer.tokenize("ภาษาไทยง่ายนิดเดียว"), vec!["ภาษา", "ไทย", "ง่าย", "นิดเดียว"]);
+/// ```
+///
+/// # Japanese and Korean
+///
+/// Python's wordfreq tokenizes Japanese and Korean with MeCab, using IPADIC and mecab-ko-dic.
+/// With the `lindera` feature, this class does the same with [lindera](https://docs.rs/lindera)
+/// and the same dictionaries embedded in the binary, producing the token boundaries of `mecab_tokenize`.
+/// As in `mecab_tokenize`, the text is NFKC-normalized before tokenization,
+/// so the tokens are returned in the normalized form.
+///
+/// ```
+/// # #[cfg(feature = "lindera")]
+/// # {
+/// use wordfreq::Tokenizer;
+/// let tokenizer = Tokenizer::new("ja").unwrap();
+/// assert_eq!(
+///     tokenizer.tokenize("Ｕターンは禁止です。"),
+///     vec!["U", "ターン", "は", "禁止", "です"]
+/// );
+/// # }
+/// ```
+///
+/// Without the feature, runs of Japanese characters are kept as single tokens,
+/// and Korean is split by spaces.
+#[derive(Clone)]
+pub struct Tokenizer {
+    token_re: Regex,
+    segment_re: Option<Regex>,
+    segmenter: Option<Segmenter>,
+    chinese: bool,
+    #[cfg(feature = "lindera")]
+    mecab: Option<lindera::segmenter::Segmenter>,
+}
+
+impl Tokenizer {
+    /// Creates a new Tokenizer for the given language.
+    ///
+    /// # Arguments
+    ///
+    /// - `language_tag`: Language tag, which should be one of left keys in [`language::LIKELY_SUBTAGS`].
+    pub fn new(language_tag: &str) -> Result<Self> {
+        let language_tag = language::maximize_subtag(language_tag).ok_or_else(|| anyhow!(
+            "{language_tag} is an unexpected language tag. You must input a language tag defined in left keys of wordfreq::language::LIKELY_SUBTAGS."
+        ))?;
+        let (primary_language, script) = language::split_subtag(language_tag);
+
+        let token_re = Regex::new(&format!(
+            r"(?x)
+            # Case 1: runs of characters in scripts written without spaces
+            [{SPACELESS_CLASS}]+
+            |
+            # Case 2: numbers, possibly with separators and suffixes, such as 2,000 or 42nd
+            \d+(?:[.,:]\d+)*[[\w\p{{M}}]--[{SPACELESS_CLASS}]]*
+            |
+            # Case 3: words, possibly with inner apostrophes, such as don't
+            [[\w\p{{M}}]--[{SPACELESS_CLASS}]]+(?:['’][[\w\p{{M}}]--[{SPACELESS_CLASS}]]+)*
+            "
+        ))
+        .unwrap();
+
+        // Runs of these scripts are segmented if a dictionary is given.
+        let segment_re = if "zh" == primary_language {
+            Some(r"^\p{Han}+$")
+        } else {
+            match script {
+                "Thai" => Some(r"^\p{Thai}+$"),
+                "Laoo" => Some(r"^\p{Lao}+$"),
+                "Khmr" => Some(r"^\p{Khmer}+$"),
+                "Mymr" => Some(r"^\p{Myanmar}+$"),
+                _ => None,
+            }
+        }
+        .map(|pattern| Regex::new(pattern).unwrap());
+        let chinese = "zh" == primary_language;
+
+        #[cfg(feature = "lindera")]
+        let mecab = match primary_language {
+            "ja" => Some(Self::load_mecab("embedded://ipadic")?),
+            "ko" => Some(Self::load_mecab("embedded://ko-dic")?),
+            _ => None,
+        };
+
+        Ok(Self {
+            token_re,
+            segment_re,
+            segmenter: None,
+            chinese,
+            #[cfg(feature = "lindera")]
+            mecab,
+        })
+    }
+
+    #[cfg(feature = "lindera")]
+    fn load_mecab(uri: &str) -> Result<lindera::segmenter::Segmenter> {
+        let dictionary = lindera::dictionary::load_dictionary(uri)?;
+        Ok(lindera::segmenter::Segmenter::new(
+            lindera::mode::Mode::Normal,
+            dictionary,
+            None,
+        ))
+    }
+
+    /// Sets the dictionary used to segment text written without spaces.
+    ///
+    /// The words and frequencies of the given instance are used as the dictionary.
+    /// Currently, Chinese, Thai, Lao, Khmer, and Burmese are segmented,
+    /// and the dictionary is ignored (and not built) for other languages.
+    pub fn dictionary(self, wf: &WordFreq) -> Self {
+        if self.segment_re.is_none() {
+            return self;
+        }
+        self.segmenter(Segmenter::new(
+            wf.word_frequency_map()
+                .iter()
+                .map(|(word, &freq)| (word, freq)),
+        ))
+    }
+
+    /// Sets the word list used to segment text written without spaces,
+    /// giving the same probability to every word.
+    ///
+    /// See [`Tokenizer::dictionary()`] for the supported languages.
+    pub fn word_list<I, W>(self, words: I) -> Self
+    where
+        I: IntoIterator<Item = W>,
+        W: AsRef<str>,
+    {
+        if self.segment_re.is_none() {
+            return self;
+        }
+        self.segmenter(Segmenter::word_list(words))
+    }
+
+    fn segmenter(mut self, segmenter: Segmenter) -> Self {
+        self.segmenter = Some(if self.chinese {
+            segmenter.chinese()
+        } else {
+            segmenter
+        });
+        self
+    }
+
+    /// Splits the given text into tokens.
+    pub fn tokenize(&self, text: &str) -> Vec<String> {
+        #[cfg(feature = "lindera")]
+        if let Some(mecab) = self.mecab.as_ref() {
+            return Self::mecab_tokenize(mecab, text);
+        }
+
+        let mut tokens = vec![];
+        for m in self.token_re.find_iter(text) {
+            let token = m.as_str();
+            match (self.segment_re.as_ref(), self.segmenter.as_ref()) {
+                (Some(segment_re), Some(segmenter)) if segment_re.is_match(token) => {
+                    tokens.extend(segmenter.segment(token).into_iter().map(String::from));
+                }
+                _ => tokens.push(token.to_string()),
+            }
+        }
+        tokens
+    }
+
+    /// Port of `mecab_tokenize` in [wordfreq/mecab.py](https://github.com/rspeer/wordfreq/blob/v3.0.2/wordfreq/mecab.py),
+    /// also dropping punctuation tokens as `tokenize` does.
+    #[cfg(feature = "lindera")]
+    fn mecab_tokenize(mecab: &lindera::segmenter::Segmenter, text: &str) -> Vec<String> {
+        use std::borrow::Cow;
+        use unicode_normalization::UnicodeNormalization;
+
+        let text = text.trim().nfkc().collect::<String>();
+        // An error is returned only for broken dictionaries.
+        let tokens = mecab.segment(Cow::Borrowed(&text)).unwrap();
+        tokens
+            .into_iter()
+            .map(|token| token.surface)
+            .filter(|surface| surface.chars().any(char::is_alphanumeric))
+            .map(Cow::into_owned)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        let tokenizer = Tokenizer::new("en").unwrap();
+        assert_eq!(
+            tokenizer.tokenize("It's 12:30, the 42nd time, 802.11n!"),
+            vec!["It's", "12:30", "the", "42nd", "time", "802.11n"]
+        );
+        assert!(tokenizer.tokenize(" ... ").is_empty());
+    }
+
+    #[test]
+    fn test_tokenize_spaceless() {
+        let tokenizer = Tokenizer::new("ja").unwrap();
+        assert_eq!(
+            tokenizer.tokenize("Uターンは禁止です"),
+            vec!["U", "ターンは禁止です"]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_chinese_mixed() {
+        let wf = WordFreq::new([("北京", 1.), ("大学", 1.)]);
+        let tokenizer = Tokenizer::new("zh").unwrap().dictionary(&wf);
+        assert_eq!(
+            tokenizer.tokenize("北京大学 is in Beijing."),
+            vec!["北京", "大学", "is", "in", "Beijing"]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_southeast_asian() {
+        let wf = WordFreq::new([("ພາສາ", 1.), ("ລາວ", 1.)]);
+        let tokenizer = Tokenizer::new("lo").unwrap().dictionary(&wf);
+        assert_eq!(tokenizer.tokenize("ພາສາລາວ"), vec!["ພາສາ", "ລາວ"]);
+
+        let tokenizer = Tokenizer::new("km").unwrap().word_list(["ភាសា", "ខ្មែរ"]);
+        assert_eq!(tokenizer.tokenize("ភាសាខ្មែរ"), vec!["ភាសា", "ខ្មែរ"]);
+
+        let tokenizer = Tokenizer::new("my").unwrap().word_list(["မြန်မာ", "စာ"]);
+        assert_eq!(tokenizer.tokenize("မြန်မာစာ"), vec!["မြန်မာ", "စာ"]);
+
+        // Thai text is not segmented with the Chinese tokenizer, and vice versa.
+        let tokenizer = Tokenizer::new("zh").unwrap().word_list(["ภาษา", "ไทย"]);
+        assert_eq!(tokenizer.tokenize("ภาษาไทย"), vec!["ภาษาไทย"]);
+    }
+
+    #[test]
+    fn test_dictionary_ignored() {
+        let wf = WordFreq::new([("las", 1.), ("vegas", 1.)]);
+        let tokenizer = Tokenizer::new("en").unwrap().dictionary(&wf);
+        assert!(tokenizer.segmenter.is_none());
+        let tokenizer = Tokenizer::new("fr").unwrap().word_list(["las", "vegas"]);
+        assert!(tokenizer.segmenter.is_none());
+        let tokenizer = Tokenizer::new("th").unwrap().dictionary(&wf);
+        assert!(tokenizer.segmenter.is_some());
+    }
+
+    #[test]
+    fn test_tokenize_word_list() {
+        let tokenizer = Tokenizer::new("th")
+            .unwrap()
+            .word_list(["กข", "กขค", "คงจ"]);
+        assert_eq!(tokenizer.tokenize("กขคงจ"), vec!["กข", "คงจ"]);
+
+        // Tone marks stay with their base characters.
+        let tokenizer = Tokenizer::new("th").unwrap().word_list(["ภาษา"]);
+        assert_eq!(
+            tokenizer.tokenize("ภาษาง่าย"),
+            vec!["ภาษา", "ง\u{e48}", "า", "ย"]
+        );
+    }
+
+    #[cfg(feature = "lindera")]
+    #[test]
+    fn test_tokenize_mecab() {
+        let tokenizer = Tokenizer::new("ja").unwrap();
+        assert_eq!(
+            tokenizer.tokenize("関西国際空港限定トートバッグ"),
+            vec!["関西国際空港", "限定", "トートバッグ"]
+        );
+        let tokenizer = Tokenizer::new("ko").unwrap();
+        assert_eq!(
+            tokenizer.tokenize("하네다공항한정토트백."),
+            vec!["하네다", "공항", "한정", "토트백"]
+        );
+    }
+
+    #[test]
+    fn test_unexpected_langtag() {
+        let tokenizer = Tokenizer::new("hoge");
+        assert!(tokenizer.is_err());
+    }
+}
@@ -3,6 +3,12 @@
 //
 // The code is a port from https://github.com/rspeer/wordfreq/blob/v3.0.2/wordfreq/transliterate.py
 // together with the comments, following the MIT-license.
+//! Transliteration of multi-script languages.
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
 use hashbrown::HashMap;
 
 const SR_LATN_TABLE: &[(char, &str)] = &[
@@ -225,40 +231,726 @@ const AZ_LATN_TABLE: &[(char, &str)] = &[
     ('ү', "ü"),
 ];
 
+// Uzbek Cyrillic to the official Latin alphabet of 1995.
+const UZ_LATN_TABLE: &[(char, &str)] = &[
+    ('А', "A"),
+    ('а', "a"),
+    ('Б', "B"),
+    ('б', "b"),
+    ('В', "V"),
+    ('в', "v"),
+    ('Г', "G"),
+    ('г', "g"),
+    ('Ғ', "Gʻ"),
+    ('ғ', "gʻ"),
+    ('Д', "D"),
+    ('д', "d"),
+    ('Е', "E"),
+    ('е', "e"),
+    ('Ё', "Yo"),
+    ('ё', "yo"),
+    ('Ж', "J"),
+    ('ж', "j"),
+    ('З', "Z"),
+    ('з', "z"),
+    ('И', "I"),
+    ('и', "i"),
+    ('Й', "Y"),
+    ('й', "y"),
+    ('К', "K"),
+    ('к', "k"),
+    ('Қ', "Q"),
+    ('қ', "q"),
+    ('Л', "L"),
+    ('л', "l"),
+    ('М', "M"),
+    ('м', "m"),
+    ('Н', "N"),
+    ('н', "n"),
+    ('О', "O"),
+    ('о', "o"),
+    ('П', "P"),
+    ('п', "p"),
+    ('Р', "R"),
+    ('р', "r"),
+    ('С', "S"),
+    ('с', "s"),
+    ('Т', "T"),
+    ('т', "t"),
+    ('У', "U"),
+    ('у', "u"),
+    ('Ў', "Oʻ"),
+    ('ў', "oʻ"),
+    ('Ф', "F"),
+    ('ф', "f"),
+    ('Х', "X"),
+    ('х', "x"),
+    ('Ҳ', "H"),
+    ('ҳ', "h"),
+    ('Ц', "Ts"),
+    ('ц', "ts"),
+    ('Ч', "Ch"),
+    ('ч', "ch"),
+    ('Ш', "Sh"),
+    ('ш', "sh"),
+    ('Ъ', "ʼ"),
+    ('ъ', "ʼ"),
+    ('Э', "E"),
+    ('э', "e"),
+    ('Ю', "Yu"),
+    ('ю', "yu"),
+    ('Я', "Ya"),
+    ('я', "ya"),
+    // Russian letters
+    ('Щ', "Shch"),
+    ('щ', "shch"),
+    ('Ы', "I"),
+    ('ы', "i"),
+    ('Ь', ""),
+    ('ь', ""),
+];
+
+// Kazakh Cyrillic to the Latin alphabet of 2021.
+const KK_LATN_TABLE: &[(char, &str)] = &[
+    ('А', "A"),
+    ('а', "a"),
+    ('Ә', "Ä"),
+    ('ә', "ä"),
+    ('Б', "B"),
+    ('б', "b"),
+    ('В', "V"),
+    ('в', "v"),
+    ('Г', "G"),
+    ('г', "g"),
+    ('Ғ', "Ğ"),
+    ('ғ', "ğ"),
+    ('Д', "D"),
+    ('д', "d"),
+    ('Е', "E"),
+    ('е', "e"),
+    ('Ж', "J"),
+    ('ж', "j"),
+    ('З', "Z"),
+    ('з', "z"),
+    ('И', "İ"),
+    ('и', "i"),
+    ('Й', "İ"),
+    ('й', "i"),
+    ('К', "K"),
+    ('к', "k"),
+    ('Қ', "Q"),
+    ('қ', "q"),
+    ('Л', "L"),
+    ('л', "l"),
+    ('М', "M"),
+    ('м', "m"),
+    ('Н', "N"),
+    ('н', "n"),
+    ('Ң', "Ñ"),
+    ('ң', "ñ"),
+    ('О', "O"),
+    ('о', "o"),
+    ('Ө', "Ö"),
+    ('ө', "ö"),
+    ('П', "P"),
+    ('п', "p"),
+    ('Р', "R"),
+    ('р', "r"),
+    ('С', "S"),
+    ('с', "s"),
+    ('Т', "T"),
+    ('т', "t"),
+    ('У', "U"),
+    ('у', "u"),
+    ('Ұ', "Ū"),
+    ('ұ', "ū"),
+    ('Ү', "Ü"),
+    ('ү', "ü"),
+    ('Ф', "F"),
+    ('ф', "f"),
+    ('Х', "H"),
+    ('х', "h"),
+    ('Һ', "H"),
+    ('һ', "h"),
+    ('Ш', "Ş"),
+    ('ш', "ş"),
+    ('Ы', "Y"),
+    ('ы', "y"),
+    ('І', "I"),
+    ('і', "ı"),
+    // Russian letters
+    ('Ё', "İo"),
+    ('ё', "io"),
+    ('Ц', "Ts"),
+    ('ц', "ts"),
+    ('Ч', "Ç"),
+    ('ч', "ç"),
+    ('Щ', "Şş"),
+    ('щ', "şş"),
+    ('Ъ', ""),
+    ('ъ', ""),
+    ('Ь', ""),
+    ('ь', ""),
+    ('Э', "E"),
+    ('э', "e"),
+    ('Ю', "İu"),
+    ('ю', "iu"),
+    ('Я', "İa"),
+    ('я', "ia"),
+];
+
+// Tajik Cyrillic to Latin, following the common romanization of Tajik.
+const TG_LATN_TABLE: &[(char, &str)] = &[
+    ('А', "A"),
+    ('а', "a"),
+    ('Б', "B"),
+    ('б', "b"),
+    ('В', "V"),
+    ('в', "v"),
+    ('Г', "G"),
+    ('г', "g"),
+    ('Ғ', "Gh"),
+    ('ғ', "gh"),
+    ('Д', "D"),
+    ('д', "d"),
+    ('Е', "E"),
+    ('е', "e"),
+    ('Ё', "Yo"),
+    ('ё', "yo"),
+    ('Ж', "Zh"),
+    ('ж', "zh"),
+    ('З', "Z"),
+    ('з', "z"),
+    ('И', "I"),
+    ('и', "i"),
+    ('Ӣ', "Ī"),
+    ('ӣ', "ī"),
+    ('Й', "Y"),
+    ('й', "y"),
+    ('К', "K"),
+    ('к', "k"),
+    ('Қ', "Q"),
+    ('қ', "q"),
+    ('Л', "L"),
+    ('л', "l"),
+    ('М', "M"),
+    ('м', "m"),
+    ('Н', "N"),
+    ('н', "n"),
+    ('О', "O"),
+    ('о', "o"),
+    ('П', "P"),
+    ('п', "p"),
+    ('Р', "R"),
+    ('р', "r"),
+    ('С', "S"),
+    ('с', "s"),
+    ('Т', "T"),
+    ('т', "t"),
+    ('У', "U"),
+    ('у', "u"),
+    ('Ӯ', "Ū"),
+    ('ӯ', "ū"),
+    ('Ф', "F"),
+    ('ф', "f"),
+    ('Х', "Kh"),
+    ('х', "kh"),
+    ('Ҳ', "H"),
+    ('ҳ', "h"),
+    ('Ч', "Ch"),
+    ('ч', "ch"),
+    ('Ҷ', "J"),
+    ('ҷ', "j"),
+    ('Ш', "Sh"),
+    ('ш', "sh"),
+    ('Ъ', "ʼ"),
+    ('ъ', "ʼ"),
+    ('Э', "E"),
+    ('э', "e"),
+    ('Ю', "Yu"),
+    ('ю', "yu"),
+    ('Я', "Ya"),
+    ('я', "ya"),
+    // Russian letters
+    ('Ц', "Ts"),
+    ('ц', "ts"),
+    ('Щ', "Shch"),
+    ('щ', "shch"),
+    ('Ы', "Y"),
+    ('ы', "y"),
+    ('Ь', ""),
+    ('ь', ""),
+];
+
+// Mongolian Cyrillic to Latin, following MNS 5217:2012.
+const MN_LATN_TABLE: &[(char, &str)] = &[
+    ('А', "A"),
+    ('а', "a"),
+    ('Б', "B"),
+    ('б', "b"),
+    ('В', "V"),
+    ('в', "v"),
+    ('Г', "G"),
+    ('г', "g"),
+    ('Д', "D"),
+    ('д', "d"),
+    ('Е', "Ye"),
+    ('е', "ye"),
+    ('Ё', "Yo"),
+    ('ё', "yo"),
+    ('Ж', "J"),
+    ('ж', "j"),
+    ('З', "Z"),
+    ('з', "z"),
+    ('И', "I"),
+    ('и', "i"),
+    ('Й', "I"),
+    ('й', "i"),
+    ('К', "K"),
+    ('к', "k"),
+    ('Л', "L"),
+    ('л', "l"),
+    ('М', "M"),
+    ('м', "m"),
+    ('Н', "N"),
+    ('н', "n"),
+    ('О', "O"),
+    ('о', "o"),
+    ('Ө', "Ö"),
+    ('ө', "ö"),
+    ('П', "P"),
+    ('п', "p"),
+    ('Р', "R"),
+    ('р', "r"),
+    ('С', "S"),
+    ('с', "s"),
+    ('Т', "T"),
+    ('т', "t"),
+    ('У', "U"),
+    ('у', "u"),
+    ('Ү', "Ü"),
+    ('ү', "ü"),
+    ('Ф', "F"),
+    ('ф', "f"),
+    ('Х', "Kh"),
+    ('х', "kh"),
+    ('Ц', "Ts"),
+    ('ц', "ts"),
+    ('Ч', "Ch"),
+    ('ч', "ch"),
+    ('Ш', "Sh"),
+    ('ш', "sh"),
+    ('Щ', "Sh"),
+    ('щ', "sh"),
+    ('Ъ', "I"),
+    ('ъ', "i"),
+    ('Ы', "Y"),
+    ('ы', "y"),
+    ('Ь', "I"),
+    ('ь', "i"),
+    ('Э', "E"),
+    ('э', "e"),
+    ('Ю', "Yu"),
+    ('ю', "yu"),
+    ('Я', "Ya"),
+    ('я', "ya"),
+];
+
+// Vowels in the Uzbek Cyrillic alphabet, used in the context rules.
+const UZ_VOWELS: &str = "аеёиоуўэюя";
+
+/// Transliteration rules for multi-script languages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Transliteration {
+    /// Serbian Cyrillic to Latin, also used for Bosnian and Montenegrin.
     SrLatn,
+    /// Azerbaijani Cyrillic to Latin.
     AzLatn,
+    /// Uzbek Cyrillic to Latin.
+    UzLatn,
+    /// Kazakh Cyrillic to Latin.
+    KkLatn,
+    /// Tajik Cyrillic to Latin.
+    TgLatn,
+    /// Mongolian Cyrillic to Latin.
+    MnLatn,
 }
 
+impl Transliteration {
+    /// Returns the transliteration used to standardize the given primary language subtag, if any.
+    ///
+    /// ```
+    /// use wordfreq::transliterate::Transliteration;
+    /// assert_eq!(Transliteration::from_language("bs"), Some(Transliteration::SrLatn));
+    /// assert_eq!(Transliteration::from_language("en"), None);
+    /// ```
+    pub fn from_language(primary_language: &str) -> Option<Self> {
+        match primary_language {
+            "sr" | "bs" | "cnr" => Some(Self::SrLatn),
+            "az" => Some(Self::AzLatn),
+            "uz" => Some(Self::UzLatn),
+            "kk" => Some(Self::KkLatn),
+            "tg" => Some(Self::TgLatn),
+            "mn" => Some(Self::MnLatn),
+            _ => None,
+        }
+    }
+
+    const fn table(self) -> &'static [(char, &'static str)] {
+        match self {
+            Self::SrLatn => SR_LATN_TABLE,
+            Self::AzLatn => AZ_LATN_TABLE,
+            Self::UzLatn => UZ_LATN_TABLE,
+            Self::KkLatn => KK_LATN_TABLE,
+            Self::TgLatn => TG_LATN_TABLE,
+            Self::MnLatn => MN_LATN_TABLE,
+        }
+    }
+
+    /// Context rules taking precedence over the table.
+    fn rules(self) -> Vec<Rule> {
+        match self {
+            // Montenegrin letters written with a combining acute accent,
+            // which have no precomposed Cyrillic forms.
+            Self::SrLatn => vec![Rule::new("с\u{0301}", "ś"), Rule::new("з\u{0301}", "ź")],
+            // In Uzbek, е is written as "ye" at the start of a word and after a vowel,
+            // and ц is written as "s" except after a vowel.
+            Self::UzLatn => vec![
+                Rule::new("е", "ye").before(Context::WordBoundary),
+                Rule::new("е", "ye").before(Context::OneOf(format!("{UZ_VOWELS}ъь"))),
+                Rule::new("ц", "s").before(Context::WordBoundary),
+                Rule::new("ц", "s").before(Context::NotOneOf(UZ_VOWELS.to_string())),
+            ],
+            _ => vec![],
+        }
+    }
+}
+
+/// Condition on the character neighboring a match of [`Rule`].
+///
+/// Characters are compared in lowercase.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Context {
+    /// Always matches.
+    Any,
+    /// Matches if there is no neighboring letter, i.e., at the start or end of a word.
+    WordBoundary,
+    /// Matches if the neighboring character is one of the given characters.
+    OneOf(String),
+    /// Matches if the neighboring character is a letter not included in the given characters.
+    NotOneOf(String),
+}
+
+impl Context {
+    fn matches(&self, c: Option<char>) -> bool {
+        match self {
+            Self::Any => true,
+            Self::WordBoundary => !c.is_some_and(char::is_alphabetic),
+            Self::OneOf(chars) => c.is_some_and(|c| chars.contains(c)),
+            Self::NotOneOf(chars) => c.is_some_and(|c| c.is_alphabetic() && !chars.contains(c)),
+        }
+    }
+}
+
+/// Transliteration rule that maps a sequence of characters depending on its context.
+///
+/// Rules are written in lowercase and matched case-insensitively.
+/// The replacement follows the case of the matched text: it is capitalized for a
+/// capitalized word, and fully uppercased for an all-caps word.
+///
+/// ```
+/// use wordfreq::transliterate::{Context, Rule, Transliterater, Transliteration};
+/// // With the Serbian table, Russian е is written as "je" at the start of a word, as in Serbian.
+/// let transliterater = Transliterater::new(Transliteration::SrLatn)
+///     .rule(Rule::new("е", "je").before(Context::WordBoundary));
+/// assert_eq!(transliterater.transliterate("Ельцин"), "Jel'cin");
+/// assert_eq!(transliterater.transliterate("ЕЛЬЦИН"), "JEL'CIN");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    from: Vec<char>,
+    to: String,
+    before: Context,
+    after: Context,
+}
+
+impl Rule {
+    /// Creates a rule replacing `from` with `to` in any context.
+    pub fn new(from: &str, to: &str) -> Self {
+        Self {
+            from: from.chars().map(to_lowercase).collect(),
+            to: to.to_string(),
+            before: Context::Any,
+            after: Context::Any,
+        }
+    }
+
+    /// Sets the condition on the character before the match (lookbehind).
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn before(mut self, context: Context) -> Self {
+        self.before = context;
+        self
+    }
+
+    /// Sets the condition on the character after the match (lookahead).
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn after(mut self, context: Context) -> Self {
+        self.after = context;
+        self
+    }
+
+    fn matches(&self, lowered: &[char], i: usize) -> bool {
+        lowered[i..].starts_with(&self.from)
+            && self.before.matches(i.checked_sub(1).map(|j| lowered[j]))
+            && self
+                .after
+                .matches(lowered.get(i + self.from.len()).cloned())
+    }
+}
+
+/// Transliterater from one script to another.
+///
+/// Characters are mapped one by one with a table, except where context rules apply.
+/// Letters mapped to multiple letters, such as Serbian Љ to "Lj", are fully uppercased in all-caps words.
+///
+/// ```
+/// use wordfreq::transliterate::{Transliterater, Transliteration};
+/// let transliterater = Transliterater::new(Transliteration::SrLatn);
+/// assert_eq!(transliterater.transliterate("Љубљана"), "Ljubljana");
+/// assert_eq!(transliterater.transliterate("ЉУБЉАНА"), "LJUBLJANA");
+///
+/// let transliterater = Transliterater::new(Transliteration::UzLatn);
+/// assert_eq!(transliterater.transliterate("Ўзбекистон"), "Oʻzbekiston");
+/// assert_eq!(transliterater.transliterate("Ер"), "Yer");
+/// ```
 #[derive(Clone)]
 pub struct Transliterater {
     table: HashMap<char, &'static str>,
+    rules: Vec<Rule>,
 }
 
 impl Transliterater {
+    /// Creates a new Transliterater with the given rules.
     pub fn new(tr: Transliteration) -> Self {
-        match tr {
-            Transliteration::SrLatn => {
-                let table = SR_LATN_TABLE.iter().cloned().collect();
-                Self { table }
-            }
-            Transliteration::AzLatn => {
-                let table = AZ_LATN_TABLE.iter().cloned().collect();
-                Self { table }
-            }
+        let table = tr.table().iter().cloned().collect();
+        Self {
+            table,
+            rules: vec![],
         }
+        .rules(tr.rules())
+    }
+
+    /// Adds a context rule, which takes precedence over the table.
+    ///
+    /// Rules matching longer sequences are tried first,
+    /// and earlier rules win among ones of the same length.
+    pub fn rule(self, rule: Rule) -> Self {
+        self.rules([rule])
+    }
+
+    fn rules<I>(mut self, rules: I) -> Self
+    where
+        I: IntoIterator<Item = Rule>,
+    {
+        self.rules.extend(rules);
+        // Stable, so that earlier rules win among ones of the same length.
+        self.rules
+            .sort_by_key(|rule| core::cmp::Reverse(rule.from.len()));
+        self
     }
 
     /// Applies the transliteration rules to the given text.
     pub fn transliterate(&self, text: &str) -> String {
+        let chars: Vec<_> = text.chars().collect();
+        let lowered: Vec<_> = chars.iter().cloned().map(to_lowercase).collect();
         let mut result = String::with_capacity(text.len());
-        for c in text.chars() {
-            if let Some(replacement) = self.table.get(&c) {
-                result.push_str(replacement);
+        let mut i = 0;
+        while i < chars.len() {
+            if let Some(rule) = self.rules.iter().find(|rule| rule.matches(&lowered, i)) {
+                let j = i + rule.from.len();
+                result.push_str(&Self::recase(&rule.to, &chars, i, j));
+                i = j;
+            } else if let Some(replacement) = self.table.get(&chars[i]) {
+                if replacement.chars().count() > 1
+                    && chars[i].is_uppercase()
+                    && Self::is_all_caps(&chars, i, i + 1)
+                {
+                    result.push_str(&replacement.to_uppercase());
+                } else {
+                    result.push_str(replacement);
+                }
+                i += 1;
             } else {
-                result.push(c);
+                result.push(chars[i]);
+                i += 1;
             }
         }
         result
     }
+
+    /// Follows the case of chars[i..j] for the lowercase replacement.
+    fn recase(to: &str, chars: &[char], i: usize, j: usize) -> String {
+        if !chars[i].is_uppercase() {
+            to.to_string()
+        } else if Self::is_all_caps(chars, i, j) {
+            to.to_uppercase()
+        } else {
+            let mut it = to.chars();
+            it.next().map_or_else(String::new, |c| {
+                c.to_uppercase().chain(it).collect::<String>()
+            })
+        }
+    }
+
+    /// Checks if the uppercase chars[i..j] is in an all-caps word, looking at the
+    /// next letter, or the previous one at the end of a word.
+    fn is_all_caps(chars: &[char], i: usize, j: usize) -> bool {
+        let letters: Vec<_> = chars[i..j].iter().filter(|c| c.is_alphabetic()).collect();
+        if letters.len() > 1 {
+            return letters.iter().all(|c| c.is_uppercase());
+        }
+        chars.get(j).filter(|c| c.is_alphabetic()).map_or_else(
+            || {
+                i.checked_sub(1)
+                    .is_some_and(|k| chars[k].is_alphabetic() && chars[k].is_uppercase())
+            },
+            |next| next.is_uppercase(),
+        )
+    }
+}
+
+fn to_lowercase(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Letters of the Serbian Cyrillic alphabet.
+    const SR_ALPHABET: &str = "АБВГДЂЕЖЗИЈКЛЉМНЊОПРСТЋУФХЦЧЏШабвгдђежзијклљмнњопрстћуфхцчџш";
+
+    // Letters of the Azerbaijani Cyrillic alphabet.
+    const AZ_ALPHABET: &str = "АБВГҒДЕӘЖЗИЫЈКҜЛМНОӨПРСТУҮФХҺЧҸШабвгғдеәжзиыјкҝлмноөпрстуүфхһчҹш";
+
+    /// Transliterates Latin text back to Cyrillic with the inverse of the table
+    /// restricted to the alphabet, matching the longest sequence first.
+    fn inverse(tr: Transliteration, alphabet: &str, text: &str) -> String {
+        let transliterater = Transliterater::new(tr);
+        let mut inv = HashMap::new();
+        for c in alphabet.chars() {
+            let latn = transliterater.table[&c].to_string();
+            // The table must be injective on the alphabet to round-trip.
+            assert_eq!(inv.insert(latn.clone(), c), None, "{tr:?}: {latn}");
+            if c.is_uppercase() && latn.chars().count() > 1 {
+                inv.insert(latn.to_uppercase(), c);
+            }
+        }
+        let chars: Vec<_> = text.chars().collect();
+        let mut result = String::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let (c, len) = (1..=2)
+                .rev()
+                .filter(|&len| i + len <= chars.len())
+                .find_map(|len| {
+                    let key: String = chars[i..i + len].iter().collect();
+                    inv.get(&key).map(|&c| (c, len))
+                })
+                .unwrap_or((chars[i], 1));
+            result.push(c);
+            i += len;
+        }
+        result
+    }
+
+    #[test]
+    fn test_round_trip_sr_latn() {
+        let transliterater = Transliterater::new(Transliteration::SrLatn);
+        for word in [
+            SR_ALPHABET,
+            "Љубљана",
+            "ЉУБЉАНА",
+            "Њујорк",
+            "ЏЕП",
+            "Ђорђе Чворовић",
+            "ШЋЕПАН ЖИЖЕК",
+        ] {
+            let latn = transliterater.transliterate(word);
+            assert_eq!(inverse(Transliteration::SrLatn, SR_ALPHABET, &latn), word);
+        }
+    }
+
+    #[test]
+    fn test_round_trip_az_latn() {
+        let transliterater = Transliterater::new(Transliteration::AzLatn);
+        for word in [AZ_ALPHABET, "Азәрбајҹан", "ГЫЗЫЛ", "Һәјат", "Ҝөзәл шәһәр"]
+        {
+            let latn = transliterater.transliterate(word);
+            assert_eq!(inverse(Transliteration::AzLatn, AZ_ALPHABET, &latn), word);
+        }
+    }
+
+    #[test]
+    fn test_digraph_case() {
+        let transliterater = Transliterater::new(Transliteration::SrLatn);
+        assert_eq!(transliterater.transliterate("Љ"), "Lj");
+        assert_eq!(transliterater.transliterate("ЉЕ"), "LJE");
+        assert_eq!(transliterater.transliterate("Џ."), "Dž.");
+        assert_eq!(transliterater.transliterate("ПЉ"), "PLJ");
+        assert_eq!(transliterater.transliterate("Пљ"), "Plj");
+    }
+
+    #[test]
+    fn test_context_rules() {
+        let transliterater = Transliterater::new(Transliteration::UzLatn);
+        assert_eq!(transliterater.transliterate("ер"), "yer");
+        assert_eq!(transliterater.transliterate("поезд"), "poyezd");
+        assert_eq!(transliterater.transliterate("кел"), "kel");
+        assert_eq!(transliterater.transliterate("ЕР"), "YER");
+        assert_eq!(transliterater.transliterate("цирк"), "sirk");
+        assert_eq!(transliterater.transliterate("концерт"), "konsert");
+        assert_eq!(transliterater.transliterate("милиция"), "militsiya");
+
+        let transliterater = Transliterater::new(Transliteration::SrLatn);
+        assert_eq!(transliterater.transliterate("С\u{0301}утра"), "Śutra");
+    }
+
+    #[test]
+    fn test_transliterate() {
+        let cases = [
+            (Transliteration::SrLatn, "Љубљана", "Ljubljana"),
+            (Transliteration::AzLatn, "Азәрбајҹан", "Azərbaycan"),
+            (Transliteration::UzLatn, "Тошкент шаҳри", "Toshkent shahri"),
+            (
+                Transliteration::KkLatn,
+                "Қазақстан Республикасы",
+                "Qazaqstan Respublikasy",
+            ),
+            (Transliteration::TgLatn, "Тоҷикистон", "Tojikiston"),
+            (
+                Transliteration::MnLatn,
+                "Улаанбаатар хот",
+                "Ulaanbaatar khot",
+            ),
+        ];
+        for (tr, text, expected) in cases {
+            assert_eq!(Transliterater::new(tr).transliterate(text), expected);
+        }
+    }
+
+    #[test]
+    fn test_tables_have_no_duplicates() {
+        for tr in [
+            Transliteration::SrLatn,
+            Transliteration::AzLatn,
+            Transliteration::UzLatn,
+            Transliteration::KkLatn,
+            Transliteration::TgLatn,
+            Transliteration::MnLatn,
+        ] {
+            let table = Transliterater::new(tr).table;
+            assert_eq!(table.len(), tr.table().len(), "{tr:?}");
+        }
+    }
 }